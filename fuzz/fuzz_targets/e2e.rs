@@ -16,14 +16,18 @@ fuzz_target!(|inputs: Vec<&str>| {
         }
     }
 
-    let router = builder.build();
+    let router = builder.build_ref();
 
     for (_, input) in &inserted {
         assert!(router.search(input).is_some());
     }
 
     for input in &inputs {
-        let _search = router.search(input);
+        let expected = builder.search(input).map(|search| search.template());
+        assert_eq!(
+            router.search(input).map(|search| search.template()),
+            expected
+        );
     }
 
     let mut display = String::new();
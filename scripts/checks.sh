@@ -15,8 +15,8 @@ zizmor --pedantic .github
 cargo fmt --all --check
 cargo shear --locked
 cargo deny check
-cargo clippy --locked --workspace --all-targets
-cargo build --locked --workspace --all-targets
-cargo nextest run --locked --workspace --no-tests pass
-cargo test --locked --workspace --doc
-cargo doc --locked --workspace --no-deps
+cargo clippy --locked --workspace --all-targets --all-features
+cargo build --locked --workspace --all-targets --all-features
+cargo nextest run --locked --workspace --all-features --no-tests pass
+cargo test --locked --workspace --all-features --doc
+cargo doc --locked --workspace --all-features --no-deps
@@ -0,0 +1,436 @@
+use alloc::collections::VecDeque;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Range;
+use core::{fmt, iter};
+
+use crate::builder::RouterBuilder;
+use crate::parser::{Part, Template};
+use crate::router::Router;
+
+/// Two templates that can match the same path.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Ambiguity {
+    /// The first overlapping template.
+    pub first: String,
+
+    /// The second overlapping template.
+    pub second: String,
+
+    /// An example path matched by both templates.
+    pub witness: String,
+
+    /// The template the router matches for the witness.
+    pub winner: String,
+}
+
+impl fmt::Display for Ambiguity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "`{}` and `{}` both match `{}`, `{}` wins",
+            self.first, self.second, self.witness, self.winner
+        )
+    }
+}
+
+/// A template that no sampled path reaches, because other templates take priority.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct DeadRoute {
+    /// The unreachable template.
+    pub template: String,
+
+    /// An example path matched by the template.
+    pub witness: String,
+
+    /// The template the router matches for the witness instead, if any.
+    pub winner: Option<String>,
+}
+
+impl fmt::Display for DeadRoute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}` is likely never matched", self.template)?;
+        match &self.winner {
+            Some(winner) => write!(f, ", `{}` goes to `{winner}`", self.witness),
+            None => write!(f, ", `{}` matches nothing", self.witness),
+        }
+    }
+}
+
+/// A single matching step of a template.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+enum Token {
+    /// Exactly this character.
+    Char(char),
+    /// One or more characters, excluding `/`.
+    Segment,
+    /// One or more characters.
+    Any,
+}
+
+/// A template flattened into tokens, in path order.
+#[derive(Clone, Debug)]
+pub(crate) struct Pattern {
+    tokens: Vec<Token>,
+    /// The token ranges between slashes, unless a wildcard can span multiple segments.
+    segments: Option<Vec<Range<usize>>>,
+}
+
+impl Pattern {
+    pub(crate) fn new(template: &Template<'_>) -> Self {
+        let mut tokens = vec![];
+        for part in template.parts.iter().rev() {
+            match part {
+                Part::Static { prefix } => {
+                    tokens.extend(String::from_utf8_lossy(prefix).chars().map(Token::Char));
+                }
+                Part::Dynamic { .. } => tokens.push(Token::Segment),
+                Part::Wildcard { .. } => tokens.push(Token::Any),
+            }
+        }
+
+        let segments = (!tokens.contains(&Token::Any)).then(|| {
+            let mut segments = vec![];
+            let mut start = 0;
+            for (index, token) in tokens.iter().enumerate() {
+                if *token == Token::Char('/') {
+                    segments.push(start..index);
+                    start = index + 1;
+                }
+            }
+
+            segments.push(start..tokens.len());
+            segments
+        });
+
+        Self { tokens, segments }
+    }
+
+    /// Whether a cheap comparison already rules out any overlap.
+    fn disjoint(&self, other: &Self) -> bool {
+        if differs(&self.tokens, &other.tokens) {
+            return true;
+        }
+
+        let (Some(first), Some(second)) = (&self.segments, &other.segments) else {
+            return false;
+        };
+
+        first.len() != second.len()
+            || first
+                .iter()
+                .zip(second)
+                .any(|(a, b)| differs(&self.tokens[a.clone()], &other.tokens[b.clone()]))
+    }
+
+    /// Finds the shortest path matched by both patterns, if any.
+    ///
+    /// Runs a breadth-first search over the product of both patterns.
+    /// Parameters are filled with `x` where possible, for readable witnesses.
+    pub(crate) fn overlap(&self, other: &Self) -> Option<String> {
+        if self.disjoint(other) {
+            return None;
+        }
+
+        let (first, second) = (&self.tokens, &other.tokens);
+
+        let mut alphabet = vec![];
+        for token in first.iter().chain(second) {
+            if let Token::Char(char) = *token {
+                if char != 'x' && !alphabet.contains(&char) {
+                    alphabet.push(char);
+                }
+            }
+        }
+
+        alphabet.sort_unstable();
+        alphabet.insert(0, 'x');
+
+        let width = 2 * (second.len() + 1);
+        let index = |(a, b): (State, State)| {
+            (2 * a.0 + usize::from(a.1)) * width + 2 * b.0 + usize::from(b.1)
+        };
+
+        let start = ((0, false), (0, false));
+        let mut parents: Vec<Option<(usize, char)>> = vec![None; 2 * (first.len() + 1) * width];
+        let mut visited = vec![false; parents.len()];
+        visited[index(start)] = true;
+
+        let mut queue = VecDeque::from([start]);
+        while let Some(current @ (a, b)) = queue.pop_front() {
+            if accepts(first, a) && accepts(second, b) {
+                let mut chars = vec![];
+                let mut position = index(current);
+                while let Some((parent, char)) = parents[position] {
+                    chars.push(char);
+                    position = parent;
+                }
+
+                return Some(chars.into_iter().rev().collect());
+            }
+
+            for &char in &alphabet {
+                for next_a in step(first, a, char) {
+                    for next_b in step(second, b, char) {
+                        let next = index((next_a, next_b));
+                        if !visited[next] {
+                            visited[next] = true;
+                            parents[next] = Some((index(current), char));
+                            queue.push_back((next_a, next_b));
+                        }
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    /// An example path matched by this pattern.
+    ///
+    /// Parameters are filled with the given character, and wildcards with the given text.
+    fn sample(&self, filler: char, wildcard: &str) -> String {
+        let mut path = String::new();
+        for token in &self.tokens {
+            match token {
+                Token::Char(char) => path.push(*char),
+                Token::Segment => path.push(filler),
+                Token::Any => path.push_str(wildcard),
+            }
+        }
+
+        path
+    }
+
+    /// The static characters of this pattern.
+    fn chars(&self) -> impl Iterator<Item = char> + '_ {
+        self.tokens.iter().filter_map(|token| match token {
+            Token::Char(char) => Some(*char),
+            Token::Segment | Token::Any => None,
+        })
+    }
+
+    /// The number of `/` delimited segments this pattern spans, without wildcards.
+    fn depth(&self) -> usize {
+        self.chars().filter(|&char| char == '/').count()
+    }
+}
+
+/// Whether the leading or trailing static characters differ.
+fn differs(first: &[Token], second: &[Token]) -> bool {
+    let mismatch = |pair: (&Token, &Token)| match pair {
+        (Token::Char(a), Token::Char(b)) => Some(a != b),
+        _ => None,
+    };
+
+    first
+        .iter()
+        .zip(second)
+        .map_while(mismatch)
+        .any(|differs| differs)
+        || first
+            .iter()
+            .rev()
+            .zip(second.iter().rev())
+            .map_while(mismatch)
+            .any(|differs| differs)
+}
+
+/// A position within a token list, and whether a parameter token has consumed any characters yet.
+type State = (usize, bool);
+
+/// States reachable without consuming a character.
+fn closure((index, inside): State) -> impl Iterator<Item = State> {
+    iter::once((index, inside)).chain(inside.then_some((index + 1, false)))
+}
+
+/// Consumes a single character.
+fn advance(tokens: &[Token], (index, inside): State, char: char) -> Option<State> {
+    match tokens.get(index)? {
+        Token::Char(expected) => (!inside && *expected == char).then_some((index + 1, false)),
+        Token::Segment => (char != '/').then_some((index, true)),
+        Token::Any => Some((index, true)),
+    }
+}
+
+fn step(tokens: &[Token], state: State, char: char) -> impl Iterator<Item = State> + '_ {
+    closure(state).filter_map(move |state| advance(tokens, state, char))
+}
+
+fn accepts(tokens: &[Token], state: State) -> bool {
+    closure(state).any(|state| state == (tokens.len(), false))
+}
+
+/// Parsed templates, alongside a router built from them.
+struct Table<'a> {
+    templates: Vec<(&'a str, Pattern)>,
+    router: Router<usize>,
+}
+
+impl<'a> Table<'a> {
+    #[expect(clippy::expect_used, reason = "Only valid templates are inserted")]
+    fn new(templates: &[&'a str]) -> Self {
+        let templates: Vec<(&str, Pattern)> = templates
+            .iter()
+            .filter_map(|&template| Some((template, Pattern::new(&Template::new(template).ok()?))))
+            .collect();
+
+        let mut builder = RouterBuilder::new();
+        for (index, &(template, _)) in templates.iter().enumerate() {
+            builder
+                .insert(template, index)
+                .expect("Templates from an existing builder never conflict");
+        }
+
+        Self {
+            templates,
+            router: builder.build(),
+        }
+    }
+
+    /// The position of the template the router matches for a path.
+    fn winner(&self, path: &str) -> Option<usize> {
+        self.router.search(path).map(|found| *found.data())
+    }
+}
+
+/// Finds every pair of templates that can match the same path.
+pub(crate) fn ambiguities(templates: &[&str]) -> Vec<Ambiguity> {
+    let table = Table::new(templates);
+
+    let mut ambiguities = vec![];
+    for (position, (first, first_pattern)) in table.templates.iter().enumerate() {
+        for (second, second_pattern) in &table.templates[position + 1..] {
+            let Some(witness) = first_pattern.overlap(second_pattern) else {
+                continue;
+            };
+
+            let Some(winner) = table.winner(&witness) else {
+                continue;
+            };
+
+            ambiguities.push(Ambiguity {
+                first: (*first).into(),
+                second: (*second).into(),
+                winner: table.templates[winner].0.into(),
+                witness,
+            });
+        }
+    }
+
+    ambiguities
+}
+
+/// Finds every template that no sampled path reaches.
+///
+/// This is a heuristic: a reported template may still be reachable through a path that wasn't sampled.
+/// A template that isn't reported is always reachable.
+pub(crate) fn suspected_dead_routes(templates: &[&str]) -> Vec<DeadRoute> {
+    let table = Table::new(templates);
+
+    // A filler absent from every static part can't be claimed by another template's static text.
+    let statics: Vec<char> = table
+        .templates
+        .iter()
+        .flat_map(|(_, pattern)| pattern.chars())
+        .collect();
+
+    let filler = iter::once('x')
+        .chain(char::MIN..=char::MAX)
+        .find(|char| *char != '/' && !statics.contains(char))
+        .unwrap_or('x');
+
+    // Wildcards spanning more segments than any template escape every fixed-length template.
+    let depth = table
+        .templates
+        .iter()
+        .map(|(_, pattern)| pattern.depth())
+        .max()
+        .unwrap_or_default()
+        + 1;
+
+    // Wildcards may also span leading or trailing slashes, like `/<*path>` does for `/docs/`.
+    let mut fills = vec![];
+    for filler in [filler, 'x'] {
+        for segments in 1..=depth {
+            let wildcard = vec![String::from(filler); segments].join("/");
+            fills.push((filler, format!("/{wildcard}")));
+            fills.push((filler, format!("{wildcard}/")));
+            fills.push((filler, wildcard));
+        }
+    }
+
+    let mut dead = vec![];
+    for (position, (template, pattern)) in table.templates.iter().enumerate() {
+        let mut reached = false;
+        let mut rivals = vec![];
+        for (filler, wildcard) in &fills {
+            match table.winner(&pattern.sample(*filler, wildcard)) {
+                Some(winner) if winner == position => {
+                    reached = true;
+                    break;
+                }
+                Some(winner) if !rivals.contains(&winner) => rivals.push(winner),
+                _ => {}
+            }
+        }
+
+        // Only the templates that claimed a sample are checked for a shared path, to avoid comparing every pair.
+        if reached
+            || rivals
+                .iter()
+                .filter_map(|&rival| pattern.overlap(&table.templates[rival].1))
+                .any(|overlap| table.winner(&overlap) == Some(position))
+        {
+            continue;
+        }
+
+        let witness = pattern.sample('x', "x");
+        dead.push(DeadRoute {
+            template: (*template).into(),
+            winner: table
+                .winner(&witness)
+                .map(|winner| table.templates[winner].0.into()),
+            witness,
+        });
+    }
+
+    dead
+}
+
+#[cfg(test)]
+mod tests {
+    use similar_asserts::assert_eq;
+
+    use super::*;
+
+    fn overlap(first: &str, second: &str) -> Option<String> {
+        let first = Pattern::new(&Template::new(first).unwrap());
+        let second = Pattern::new(&Template::new(second).unwrap());
+        first.overlap(&second)
+    }
+
+    #[test]
+    fn analysis_overlap() {
+        assert_eq!(
+            overlap("/files/<name>.pdf", "/files/<*path>"),
+            Some("/files/x.pdf".into())
+        );
+
+        assert_eq!(overlap("/<a>/<b>", "/<*path>/edit"), Some("/x/edit".into()));
+
+        assert_eq!(overlap("/x/<*rest>", "/<name>/y"), Some("/x/y".into()));
+
+        assert_eq!(overlap("/café/<id>", "/<name>/1"), Some("/café/1".into()));
+    }
+
+    #[test]
+    fn analysis_overlap_disjoint() {
+        assert_eq!(overlap("/users/<id>", "/posts/<id>"), None);
+        assert_eq!(overlap("/<id>", "/<a>/<b>"), None);
+        assert_eq!(overlap("/<a>/edit", "/<b>/new"), None);
+        assert_eq!(overlap("/<*path>", "/"), None);
+    }
+}
@@ -1,8 +1,8 @@
 use crate::node::Node;
 
 /// Pre-computed path length bounds for pruning during search.
-#[derive(Clone, Debug)]
-pub(crate) struct Bounds {
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Bounds {
     /// Minimum remaining path bytes to reach any match.
     shortest: usize,
 
@@ -20,22 +20,26 @@ impl Default for Bounds {
 }
 
 impl Bounds {
-    pub(crate) fn compute<S, T>(node: &Node<S, T>) -> Self {
+    pub(crate) fn compute<S>(node: &Node<S>) -> Self {
         Self {
             shortest: Self::compute_shortest(node),
             longest: Self::compute_longest(node),
         }
     }
 
-    pub(crate) const fn shortest(&self) -> usize {
+    /// The minimum remaining path bytes to reach any match.
+    #[must_use]
+    pub const fn shortest(&self) -> usize {
         self.shortest
     }
 
-    pub(crate) const fn longest(&self) -> usize {
+    /// The maximum remaining path bytes that could still match.
+    #[must_use]
+    pub const fn longest(&self) -> usize {
         self.longest
     }
 
-    fn compute_shortest<S, T>(node: &Node<S, T>) -> usize {
+    fn compute_shortest<S>(node: &Node<S>) -> usize {
         // A node with data can match here with 0 remaining bytes.
         if node.data.is_some() {
             return 0;
@@ -71,7 +75,7 @@ impl Bounds {
             .unwrap_or(usize::MAX)
     }
 
-    fn compute_longest<S, T>(node: &Node<S, T>) -> usize {
+    fn compute_longest<S>(node: &Node<S>) -> usize {
         // Parameters can consume any input.
         if node.has_parameters() {
             return usize::MAX;
@@ -1,17 +1,27 @@
+use alloc::collections::{BTreeMap, BTreeSet};
 use alloc::vec;
 use alloc::vec::Vec;
+use core::panic::Location;
+use core::ptr;
 
+use crate::analysis::{self, Ambiguity, DeadRoute};
 use crate::compiler::Compiler;
-use crate::errors::InsertError;
-use crate::node::Data;
+use crate::coverage::Counter;
+use crate::entry::Entry;
+use crate::errors::{ExtendError, InsertError, InsertFailure};
+use crate::lint::{self, Lint, LintConfig};
+use crate::node::{Data, Value};
+use crate::parameters::{Capture as _, Captures};
 use crate::parser::{Part, Template};
-use crate::router::Router;
+use crate::router::{Match, Router};
 use crate::state::{DynamicState, EndWildcardState, RootState, StaticState, WildcardState};
 
 /// A mutable builder for constructing a [`Router`].
 #[derive(Clone)]
 pub struct RouterBuilder<T> {
-    root: BuilderNode<RootState, T>,
+    root: BuilderNode<RootState>,
+    values: Vec<Value<T>>,
+    duplicates: bool,
 }
 
 impl<T> RouterBuilder<T> {
@@ -20,14 +30,30 @@ impl<T> RouterBuilder<T> {
     pub const fn new() -> Self {
         Self {
             root: BuilderNode::new(RootState::new()),
+            values: Vec::new(),
+            duplicates: false,
         }
     }
 
+    /// Allows inserting the same template more than once.
+    ///
+    /// Each duplicate's data is kept alongside the original, in insertion order.
+    /// Use [`Match::values`](crate::Match::values) to access them.
+    /// Different templates that match the same paths are still rejected.
+    #[must_use]
+    pub const fn with_duplicates(mut self) -> Self {
+        self.duplicates = true;
+        self
+    }
+
     /// Inserts a template with associated data into the router.
     ///
+    /// The caller's location is recorded, so later conflicts can point back to it.
+    ///
     /// # Errors
     ///
     /// When the template is malformed or conflicts with an existing route.
+    /// Re-inserting an identical template is allowed when [`RouterBuilder::with_duplicates`] is enabled.
     ///
     /// # Examples
     ///
@@ -38,28 +64,416 @@ impl<T> RouterBuilder<T> {
     /// builder.insert("/hello", 1)?;
     /// # Ok::<_, Box<dyn core::error::Error>>(())
     /// ```
+    #[track_caller]
     pub fn insert(&mut self, template: &str, data: T) -> Result<(), InsertError> {
-        let mut parsed = Template::new(template)?;
+        let location = Location::caller();
+        let mut parsed = Template::insertable(template)?;
+
+        #[cfg(feature = "tracing")]
+        tracing::trace!(template, parts = ?parsed.breakdown(), "inserting template");
 
         if let Some(found) = self.root.conflict(&parsed.parts) {
-            return Err(InsertError::Conflict {
-                existing: found.template.clone().into(),
+            if !self.duplicates || *found.template != *template {
+                let error = InsertError::Conflict {
+                    existing: found.template.clone().into(),
+                    witness: parsed.witness(),
+                    location: found.location,
+                };
+
+                #[cfg(feature = "tracing")]
+                tracing::debug!(template, %error, "rejected template");
+
+                return Err(error);
+            }
+        }
+
+        if self.duplicates {
+            if let Some(existing) = self.root.find_mut(&parsed.parts) {
+                self.values[existing.slot]
+                    .duplicates
+                    .get_or_insert_default()
+                    .push(data);
+                return Ok(());
+            }
+        }
+
+        self.root.insert(
+            &mut parsed,
+            Data {
+                slot: self.values.len(),
+                template: template.into(),
+                canonical: None,
+                location,
+                hits: Counter::new(),
+            },
+        );
+
+        self.values.push(Value {
+            data,
+            duplicates: None,
+        });
+
+        Ok(())
+    }
+
+    /// Inserts a template that shares the data of an existing template.
+    ///
+    /// Searches matching the alias report the alias as the matched template, alongside the existing template as the canonical template.
+    /// Aliasing an alias shares the data of the original template.
+    /// The caller's location is recorded, as with [`RouterBuilder::insert`].
+    ///
+    /// # Errors
+    ///
+    /// When either template is malformed, the existing template was never inserted, the parameter names differ, or the alias conflicts with an existing route.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wayfind::RouterBuilder;
+    ///
+    /// let mut builder = RouterBuilder::new();
+    /// builder.insert("/users/<id>", 1)?;
+    /// builder.alias("/members/<id>", "/users/<id>")?;
+    /// assert!(builder.alias("/people/<name>", "/users/<id>").is_err());
+    ///
+    /// let router = builder.build();
+    /// let search = router.search("/members/123").unwrap();
+    /// assert_eq!(search.data(), &1);
+    /// assert_eq!(search.template(), "/members/<id>");
+    /// assert_eq!(search.canonical_template(), "/users/<id>");
+    /// # Ok::<_, Box<dyn core::error::Error>>(())
+    /// ```
+    #[track_caller]
+    pub fn alias(&mut self, template: &str, existing: &str) -> Result<(), InsertError> {
+        let location = Location::caller();
+        let mut parsed = Template::insertable(template)?;
+        let target = Template::new(existing)?;
+
+        let Some(found) = self
+            .root
+            .find_mut(&target.parts)
+            .filter(|found| *found.template == *existing)
+        else {
+            return Err(InsertError::UnknownTemplate {
+                template: existing.into(),
+            });
+        };
+
+        let slot = found.slot;
+        let canonical = found
+            .canonical
+            .clone()
+            .unwrap_or_else(|| found.template.clone());
+
+        if parsed.parameters() != target.parameters() {
+            return Err(InsertError::MismatchedParameters {
+                alias: template.into(),
+                canonical: existing.into(),
             });
         }
 
+        #[cfg(feature = "tracing")]
+        tracing::trace!(template, parts = ?parsed.breakdown(), "inserting alias");
+
+        if let Some(found) = self.root.conflict(&parsed.parts) {
+            let error = InsertError::Conflict {
+                existing: found.template.clone().into(),
+                witness: parsed.witness(),
+                location: found.location,
+            };
+
+            #[cfg(feature = "tracing")]
+            tracing::debug!(template, %error, "rejected template");
+
+            return Err(error);
+        }
+
         self.root.insert(
             &mut parsed,
             Data {
-                data,
+                slot,
                 template: template.into(),
+                canonical: Some(canonical),
+                location,
+                hits: Counter::new(),
             },
         );
 
         Ok(())
     }
 
+    /// Inserts a template, replacing the data of an identical template.
+    ///
+    /// Returns the previous data, if any.
+    /// Any duplicates of the previous data are discarded.
+    ///
+    /// # Errors
+    ///
+    /// When the template is malformed or conflicts with a different template.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wayfind::RouterBuilder;
+    ///
+    /// let mut builder = RouterBuilder::new();
+    /// assert_eq!(builder.upsert("/users/<id>", 1)?, None);
+    /// assert_eq!(builder.upsert("/users/<id>", 2)?, Some(1));
+    /// assert!(builder.upsert("/users/<name>", 3).is_err());
+    ///
+    /// let router = builder.build();
+    /// assert_eq!(router.search("/users/123").unwrap().data(), &2);
+    /// # Ok::<_, Box<dyn core::error::Error>>(())
+    /// ```
+    #[track_caller]
+    pub fn upsert(&mut self, template: &str, data: T) -> Result<Option<T>, InsertError> {
+        match self.entry(template)? {
+            Entry::Occupied(mut entry) => Ok(Some(entry.insert(data))),
+            Entry::Vacant(entry) => {
+                entry.insert(data);
+                Ok(None)
+            }
+        }
+    }
+
+    /// Gets the entry for a template, for in-place manipulation.
+    ///
+    /// # Errors
+    ///
+    /// When the template is malformed or conflicts with a different template.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wayfind::RouterBuilder;
+    ///
+    /// let mut builder = RouterBuilder::new();
+    /// builder.entry("/hits")?.or_insert(0);
+    /// *builder.entry("/hits")?.or_insert(0) += 1;
+    ///
+    /// let router = builder.build();
+    /// assert_eq!(router.search("/hits").unwrap().data(), &1);
+    /// # Ok::<_, Box<dyn core::error::Error>>(())
+    /// ```
+    pub fn entry<'a>(&'a mut self, template: &'a str) -> Result<Entry<'a, T>, InsertError> {
+        let parsed = Template::insertable(template)?;
+
+        #[cfg(feature = "tracing")]
+        tracing::trace!(template, parts = ?parsed.breakdown(), "inserting template");
+
+        let existing = self.root.conflict(&parsed.parts).map(|found| {
+            let error = InsertError::Conflict {
+                existing: found.template.clone().into(),
+                witness: parsed.witness(),
+                location: found.location,
+            };
+
+            (*found.template == *template, error)
+        });
+
+        match existing {
+            None => Ok(Entry::vacant(
+                &mut self.root,
+                &mut self.values,
+                template,
+                parsed,
+            )),
+            Some((true, error)) => self
+                .root
+                .find_mut(&parsed.parts)
+                .map(|data| {
+                    let value = &mut self.values[data.slot];
+                    Entry::occupied(data, value)
+                })
+                .ok_or(error),
+            Some((false, error)) => {
+                #[cfg(feature = "tracing")]
+                tracing::debug!(template, %error, "rejected template");
+
+                Err(error)
+            }
+        }
+    }
+
+    /// Inserts every valid template, reporting all rejected templates at once.
+    ///
+    /// Templates that fail to insert don't prevent later templates from being inserted.
+    ///
+    /// # Errors
+    ///
+    /// When any template is malformed or conflicts with another route.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wayfind::RouterBuilder;
+    ///
+    /// let mut builder = RouterBuilder::new();
+    /// let error = builder
+    ///     .try_extend([
+    ///         ("/users", 1),
+    ///         ("/users/<id", 2),
+    ///         ("/users/<id>", 3),
+    ///         ("/users", 4),
+    ///     ])
+    ///     .unwrap_err();
+    ///
+    /// assert_eq!(error.failures.len(), 2);
+    /// assert_eq!(error.failures[0].index, 1);
+    /// assert_eq!(error.failures[1].template, "/users");
+    ///
+    /// let router = builder.build();
+    /// assert!(router.search("/users/123").is_some());
+    /// ```
+    #[track_caller]
+    pub fn try_extend<'a, I: IntoIterator<Item = (&'a str, T)>>(
+        &mut self,
+        routes: I,
+    ) -> Result<(), ExtendError> {
+        let mut failures = vec![];
+        for (index, (template, data)) in routes.into_iter().enumerate() {
+            if let Err(error) = self.insert(template, data) {
+                failures.push(InsertFailure {
+                    index,
+                    template: template.into(),
+                    error,
+                });
+            }
+        }
+
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(ExtendError { failures })
+        }
+    }
+
+    /// Finds every pair of templates that can match the same path.
+    ///
+    /// Each ambiguity includes an example path matched by both, and the template that wins it.
+    /// Note that a third template may win, if it also matches the example path.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wayfind::RouterBuilder;
+    ///
+    /// let mut builder = RouterBuilder::new();
+    /// builder.insert("/files/<name>.pdf", 1)?;
+    /// builder.insert("/files/<*path>", 2)?;
+    ///
+    /// let ambiguities = builder.ambiguities();
+    /// assert_eq!(ambiguities.len(), 1);
+    /// assert_eq!(ambiguities[0].witness, "/files/x.pdf");
+    /// assert_eq!(ambiguities[0].winner, "/files/<name>.pdf");
+    /// # Ok::<_, Box<dyn core::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn ambiguities(&self) -> Vec<Ambiguity> {
+        let mut templates = vec![];
+        self.root.templates(&mut templates);
+        analysis::ambiguities(&templates)
+    }
+
+    /// Finds templates that appear to never be matched.
+    ///
+    /// A template is shadowed when every path it matches is claimed first by a higher priority template.
+    /// Each template is sampled with its parameters filled by a character that no static part contains, and its wildcards spanning up to one more segment than any template has, with or without a leading or trailing slash.
+    /// A template is reported when none of these samples reach it, nor any path it shares with the templates that claimed them.
+    ///
+    /// This is a heuristic, so a reported template may still be reachable through a path that wasn't sampled.
+    /// A template that isn't reported is always reachable.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wayfind::RouterBuilder;
+    ///
+    /// let mut builder = RouterBuilder::new();
+    /// builder.insert("/files/<*path>/", 1)?;
+    /// builder.insert("/files/<*path>/edit/", 2)?;
+    ///
+    /// let dead = builder.suspected_dead_routes();
+    /// assert_eq!(dead.len(), 1);
+    /// assert_eq!(dead[0].template, "/files/<*path>/edit/");
+    /// assert_eq!(dead[0].winner.as_deref(), Some("/files/<*path>/"));
+    /// # Ok::<_, Box<dyn core::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn suspected_dead_routes(&self) -> Vec<DeadRoute> {
+        let mut templates = vec![];
+        self.root.templates(&mut templates);
+        analysis::suspected_dead_routes(&templates)
+    }
+
+    /// Checks every template against the enabled lint rules.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wayfind::{LintConfig, LintRule, RouterBuilder};
+    ///
+    /// let mut builder = RouterBuilder::new();
+    /// builder.insert("/users/<userId>", 1)?;
+    /// builder.insert("/users/<userId>/", 2)?;
+    ///
+    /// let lints = builder.lint(LintConfig::new().without(LintRule::SnakeCase));
+    /// assert_eq!(lints.len(), 1);
+    /// assert_eq!(lints[0].rule, LintRule::TrailingSlash);
+    /// # Ok::<_, Box<dyn core::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn lint(&self, config: LintConfig) -> Vec<Lint> {
+        let mut templates = vec![];
+        self.root.templates(&mut templates);
+        lint::lint(&templates, config)
+    }
+
+    /// Searches for a matching template, without compiling the builder.
+    ///
+    /// Follows the same priority rules as [`Router::search`], so the results are identical to a compiled router.
+    /// None of the compiled pruning is available, so every candidate is tried in turn.
+    /// Subtrees that fail from a given offset are remembered, so each is only searched once.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wayfind::RouterBuilder;
+    ///
+    /// let mut builder = RouterBuilder::new();
+    /// builder.insert("/users/<id>", 1)?;
+    /// builder.insert("/users/<id>.<ext>", 2)?;
+    ///
+    /// let search = builder.search("/users/123.json").unwrap();
+    /// assert_eq!(search.data(), &2);
+    /// assert_eq!(search.parameters(), &[("id", "123"), ("ext", "json")]);
+    ///
+    /// builder.insert("/users/me.json", 3)?;
+    /// assert_eq!(builder.search("/users/me.json").unwrap().data(), &3);
+    /// # Ok::<_, Box<dyn core::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn search<'r, 'p>(&'r self, path: &'p str) -> Option<Match<'r, 'p, T>> {
+        let mut ctx = BuilderSearch {
+            parameters: Captures::new(),
+            failed: BTreeSet::new(),
+            priorities: BTreeMap::new(),
+        };
+
+        let data = self.root.search(&mut ctx, path, 0)?;
+        Some(Match::new(
+            data,
+            &self.values[data.slot],
+            path,
+            ctx.parameters,
+        ))
+    }
+
     /// Consumes the builder and produces an immutable [`Router`].
     ///
+    /// With the `tracing` feature, compilation runs inside a `build` span.
+    /// With `std` also enabled, the `compiled router` event reports how long compilation took.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -75,23 +489,102 @@ impl<T> RouterBuilder<T> {
     /// ```
     #[must_use]
     pub fn build(self) -> Router<T> {
-        Compiler::run(self.root)
+        Self::compile(self.root, self.values, self.duplicates)
+    }
+
+    /// Produces an immutable [`Router`], keeping the builder for later changes.
+    ///
+    /// Clones the builder tree and every stored value.
+    /// For large or expensive data, consider storing it behind an [`Arc`](alloc::sync::Arc).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wayfind::RouterBuilder;
+    ///
+    /// let mut builder = RouterBuilder::new();
+    /// builder.insert("/users/<id>", 1)?;
+    /// let before = builder.build_ref();
+    ///
+    /// builder.insert("/posts/<id>", 2)?;
+    /// let after = builder.build_ref();
+    ///
+    /// assert!(before.search("/posts/123").is_none());
+    /// assert!(after.search("/posts/123").is_some());
+    /// # Ok::<_, Box<dyn core::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn build_ref(&self) -> Router<T>
+    where
+        T: Clone,
+    {
+        Self::compile(self.root.clone(), self.values.clone(), self.duplicates)
+    }
+
+    fn compile(root: BuilderNode<RootState>, values: Vec<Value<T>>, duplicates: bool) -> Router<T> {
+        #[cfg(feature = "tracing")]
+        let (count, _span) = (
+            values.len(),
+            tracing::debug_span!("build", routes = values.len()).entered(),
+        );
+
+        #[cfg(all(feature = "tracing", feature = "std"))]
+        let start = std::time::Instant::now();
+
+        let router = Compiler::run(root, values, duplicates);
+
+        #[cfg(all(feature = "tracing", feature = "std"))]
+        tracing::debug!(routes = count, elapsed = ?start.elapsed(), "compiled router");
+
+        #[cfg(all(feature = "tracing", not(feature = "std")))]
+        tracing::debug!(routes = count, "compiled router");
+
+        router
+    }
+}
+
+/// Inserts every valid template, skipping any that are rejected.
+///
+/// Use [`RouterBuilder::try_extend`] to find out which templates were rejected.
+impl<'a, T> Extend<(&'a str, T)> for RouterBuilder<T> {
+    #[track_caller]
+    #[expect(
+        let_underscore_drop,
+        clippy::let_underscore_must_use,
+        reason = "Rejected templates are skipped, as documented"
+    )]
+    fn extend<I: IntoIterator<Item = (&'a str, T)>>(&mut self, iter: I) {
+        for (template, data) in iter {
+            let _ = self.insert(template, data);
+        }
+    }
+}
+
+/// Builds from every valid template, skipping any that are rejected.
+///
+/// Use [`RouterBuilder::try_extend`] to find out which templates were rejected.
+impl<'a, T> FromIterator<(&'a str, T)> for RouterBuilder<T> {
+    #[track_caller]
+    fn from_iter<I: IntoIterator<Item = (&'a str, T)>>(iter: I) -> Self {
+        let mut builder = Self::new();
+        builder.extend(iter);
+        builder
     }
 }
 
 /// A mutable builder node.
 #[derive(Clone, Debug)]
-pub(crate) struct BuilderNode<S, T> {
+pub(crate) struct BuilderNode<S> {
     pub state: S,
-    pub data: Option<Data<T>>,
+    pub data: Option<Data>,
 
-    pub static_children: Vec<BuilderNode<StaticState, T>>,
-    pub dynamic_children: Vec<BuilderNode<DynamicState, T>>,
-    pub wildcard_children: Vec<BuilderNode<WildcardState, T>>,
-    pub end_wildcard: Option<EndWildcardState<T>>,
+    pub static_children: Vec<BuilderNode<StaticState>>,
+    pub dynamic_children: Vec<BuilderNode<DynamicState>>,
+    pub wildcard_children: Vec<BuilderNode<WildcardState>>,
+    pub end_wildcard: Option<EndWildcardState>,
 }
 
-impl<S, T> BuilderNode<S, T> {
+impl<S> BuilderNode<S> {
     pub(crate) const fn new(state: S) -> Self {
         Self {
             state,
@@ -118,35 +611,44 @@ impl<S, T> BuilderNode<S, T> {
                 .all(|child| child.state.prefix.first() == Some(&b'/'))
     }
 
-    pub(crate) fn insert(&mut self, template: &mut Template<'_>, data: Data<T>) {
+    /// Inserts data at the template's position, returning the stored data.
+    ///
+    /// Any existing data at that position is replaced.
+    pub(crate) fn insert(&mut self, template: &mut Template<'_>, data: Data) -> &mut Data {
         let Some(part) = template.parts.pop() else {
-            self.data = Some(data);
-            return;
+            return self.data.insert(data);
         };
 
         match part {
             Part::Static { prefix } => self.insert_static(template, data, prefix),
             Part::Dynamic { name } => self.insert_dynamic(template, data, name),
             Part::Wildcard { name } if template.parts.is_empty() => {
-                self.insert_end_wildcard(data, name);
+                self.insert_end_wildcard(data, name)
             }
             Part::Wildcard { name } => self.insert_wildcard(template, data, name),
         }
     }
 
-    fn insert_static(&mut self, template: &mut Template<'_>, data: Data<T>, prefix: &[u8]) {
-        let Some(child) = self
+    fn insert_static(
+        &mut self,
+        template: &mut Template<'_>,
+        data: Data,
+        prefix: &[u8],
+    ) -> &mut Data {
+        let Some(position) = self
             .static_children
-            .iter_mut()
-            .find(|child| child.state.prefix[0] == prefix[0])
+            .iter()
+            .position(|child| child.state.prefix[0] == prefix[0])
         else {
-            let mut new_child = BuilderNode::new(StaticState::new(prefix));
-            new_child.insert(template, data);
-            self.static_children.push(new_child);
+            self.static_children
+                .push(BuilderNode::new(StaticState::new(prefix)));
 
-            return;
+            let index = self.static_children.len() - 1;
+            return self.static_children[index].insert(template, data);
         };
 
+        let child = &mut self.static_children[position];
+
         let common_prefix = prefix
             .iter()
             .zip(&child.state.prefix)
@@ -155,12 +657,10 @@ impl<S, T> BuilderNode<S, T> {
 
         if common_prefix >= child.state.prefix.len() {
             if common_prefix >= prefix.len() {
-                child.insert(template, data);
-            } else {
-                child.insert_static(template, data, &prefix[common_prefix..]);
+                return child.insert(template, data);
             }
 
-            return;
+            return child.insert_static(template, data, &prefix[common_prefix..]);
         }
 
         let new_child_a = BuilderNode {
@@ -178,48 +678,117 @@ impl<S, T> BuilderNode<S, T> {
 
         if prefix[common_prefix..].is_empty() {
             child.static_children = vec![new_child_a];
-            child.insert(template, data);
+            child.insert(template, data)
         } else {
             child.static_children = vec![new_child_a, new_child_b];
-            child.static_children[1].insert(template, data);
+            child.static_children[1].insert(template, data)
         }
     }
 
-    fn insert_dynamic(&mut self, template: &mut Template<'_>, data: Data<T>, name: &str) {
-        if let Some(child) = self
+    fn insert_dynamic(&mut self, template: &mut Template<'_>, data: Data, name: &str) -> &mut Data {
+        let position = self
             .dynamic_children
-            .iter_mut()
-            .find(|child| *child.state.name == *name)
-        {
-            child.insert(template, data);
-        } else {
-            let mut child = BuilderNode::new(DynamicState::new(name));
-            child.insert(template, data);
+            .iter()
+            .position(|child| *child.state.name == *name)
+            .unwrap_or_else(|| {
+                self.dynamic_children
+                    .push(BuilderNode::new(DynamicState::new(name)));
+                self.dynamic_children.len() - 1
+            });
 
-            self.dynamic_children.push(child);
-        }
+        self.dynamic_children[position].insert(template, data)
     }
 
-    fn insert_wildcard(&mut self, template: &mut Template<'_>, data: Data<T>, name: &str) {
-        if let Some(child) = self
+    fn insert_wildcard(
+        &mut self,
+        template: &mut Template<'_>,
+        data: Data,
+        name: &str,
+    ) -> &mut Data {
+        let position = self
             .wildcard_children
+            .iter()
+            .position(|child| *child.state.name == *name)
+            .unwrap_or_else(|| {
+                self.wildcard_children
+                    .push(BuilderNode::new(WildcardState::new(name)));
+                self.wildcard_children.len() - 1
+            });
+
+        self.wildcard_children[position].insert(template, data)
+    }
+
+    fn insert_end_wildcard(&mut self, data: Data, name: &str) -> &mut Data {
+        &mut self
+            .end_wildcard
+            .insert(EndWildcardState::new(name, data))
+            .data
+    }
+
+    /// Finds the data stored for exactly this template.
+    pub(crate) fn find_mut(&mut self, parts: &[Part<'_>]) -> Option<&mut Data> {
+        let Some((part, remaining)) = parts.split_last() else {
+            return self.data.as_mut();
+        };
+
+        match part {
+            Part::Static { prefix } => self.find_static_mut(remaining, prefix),
+            Part::Dynamic { name } => self
+                .dynamic_children
+                .iter_mut()
+                .find(|child| *child.state.name == **name)?
+                .find_mut(remaining),
+            Part::Wildcard { name } if remaining.is_empty() => self
+                .end_wildcard
+                .as_mut()
+                .filter(|child| *child.name == **name)
+                .map(|child| &mut child.data),
+            Part::Wildcard { name } => self
+                .wildcard_children
+                .iter_mut()
+                .find(|child| *child.state.name == **name)?
+                .find_mut(remaining),
+        }
+    }
+
+    fn find_static_mut(&mut self, parts: &[Part<'_>], prefix: &[u8]) -> Option<&mut Data> {
+        let child = self
+            .static_children
             .iter_mut()
-            .find(|child| *child.state.name == *name)
-        {
-            child.insert(template, data);
-        } else {
-            let mut child = BuilderNode::new(WildcardState::new(name));
-            child.insert(template, data);
+            .find(|child| prefix.starts_with(&child.state.prefix))?;
 
-            self.wildcard_children.push(child);
+        let rest = &prefix[child.state.prefix.len()..];
+        if rest.is_empty() {
+            child.find_mut(parts)
+        } else {
+            child.find_static_mut(parts, rest)
         }
     }
 
-    fn insert_end_wildcard(&mut self, data: Data<T>, name: &str) {
-        self.end_wildcard = Some(EndWildcardState::new(name, data));
+    /// Collects every template in this subtree.
+    pub(crate) fn templates<'a>(&'a self, templates: &mut Vec<&'a str>) {
+        if let Some(data) = &self.data {
+            templates.push(&data.template);
+        }
+
+        for child in &self.static_children {
+            child.templates(templates);
+        }
+
+        for child in &self.dynamic_children {
+            child.templates(templates);
+        }
+
+        for child in &self.wildcard_children {
+            child.templates(templates);
+        }
+
+        if let Some(child) = &self.end_wildcard {
+            templates.push(&child.data.template);
+        }
     }
 
-    pub(crate) fn conflict(&self, parts: &[Part<'_>]) -> Option<&Data<T>> {
+    pub(crate) fn conflict(&self, parts: &[Part<'_>]) -> Option<&Data> {
         let Some((part, remaining)) = parts.split_last() else {
             return self.data.as_ref();
         };
@@ -232,7 +801,7 @@ impl<S, T> BuilderNode<S, T> {
         }
     }
 
-    fn conflict_static(&self, parts: &[Part<'_>], prefix: &[u8]) -> Option<&Data<T>> {
+    fn conflict_static(&self, parts: &[Part<'_>], prefix: &[u8]) -> Option<&Data> {
         self.static_children
             .iter()
             .filter(|child| {
@@ -249,19 +818,231 @@ impl<S, T> BuilderNode<S, T> {
             })
     }
 
-    fn conflict_dynamic(&self, parts: &[Part<'_>]) -> Option<&Data<T>> {
+    fn conflict_dynamic(&self, parts: &[Part<'_>]) -> Option<&Data> {
         self.dynamic_children
             .iter()
             .find_map(|child| child.conflict(parts))
     }
 
-    fn conflict_wildcard(&self, parts: &[Part<'_>]) -> Option<&Data<T>> {
+    fn conflict_wildcard(&self, parts: &[Part<'_>]) -> Option<&Data> {
         self.wildcard_children
             .iter()
             .find_map(|child| child.conflict(parts))
     }
 
-    fn conflict_end_wildcard(&self) -> Option<&Data<T>> {
+    fn conflict_end_wildcard(&self) -> Option<&Data> {
         self.end_wildcard.as_ref().map(|child| &child.data)
     }
 }
+
+/// State kept across an uncompiled search.
+struct BuilderSearch<'r, 'p> {
+    parameters: Captures<'r, 'p>,
+
+    /// Nodes that failed to match from an offset, keyed by node address.
+    failed: BTreeSet<(usize, usize)>,
+
+    /// The parameter children of each entered node, keyed by node address.
+    priorities: BTreeMap<usize, Priorities>,
+}
+
+/// Parameter children in search order, alongside the static text that can follow each.
+struct Priorities {
+    dynamic: Vec<(usize, Vec<Vec<u8>>)>,
+    dynamic_inline: bool,
+    wildcard: Vec<(usize, Vec<Vec<u8>>)>,
+    wildcard_inline: bool,
+}
+
+impl Priorities {
+    fn new<S>(node: &BuilderNode<S>) -> Self {
+        Self {
+            dynamic: by_priority(&node.dynamic_children, |state| &state.name),
+            dynamic_inline: !node
+                .dynamic_children
+                .iter()
+                .all(BuilderNode::is_segment_only),
+            wildcard: by_priority(&node.wildcard_children, |state| &state.name),
+            wildcard_inline: !node
+                .wildcard_children
+                .iter()
+                .all(BuilderNode::is_segment_only),
+        }
+    }
+}
+
+/// An uncompiled search, mirroring the candidate order of `Node::search`.
+impl<S> BuilderNode<S> {
+    fn search<'r, 'p>(
+        &'r self,
+        ctx: &mut BuilderSearch<'r, 'p>,
+        path: &'p str,
+        offset: usize,
+    ) -> Option<&'r Data> {
+        if offset == path.len() {
+            return self.data.as_ref();
+        }
+
+        let remaining = &path.as_bytes()[offset..];
+        for child in &self.static_children {
+            if remaining.starts_with(&child.state.prefix) {
+                let end = offset + child.state.prefix.len();
+                if let Some(data) = child.search(ctx, path, end) {
+                    return Some(data);
+                }
+            }
+        }
+
+        if !self.has_parameters() || !path.is_char_boundary(offset) {
+            return None;
+        }
+
+        // A node is never its own descendant, so its entry can be taken out while searching below it.
+        let key = ptr::from_ref(self).addr();
+        let priorities = ctx
+            .priorities
+            .remove(&key)
+            .unwrap_or_else(|| Priorities::new(self));
+
+        let found = self.search_parameters(ctx, &priorities, path, offset);
+        ctx.priorities.insert(key, priorities);
+        found
+    }
+
+    fn search_parameters<'r, 'p>(
+        &'r self,
+        ctx: &mut BuilderSearch<'r, 'p>,
+        priorities: &Priorities,
+        path: &'p str,
+        offset: usize,
+    ) -> Option<&'r Data> {
+        let remaining = &path.as_bytes()[offset..];
+
+        for (index, suffixes) in &priorities.dynamic {
+            let child = &self.dynamic_children[*index];
+            let end = memchr::memchr(b'/', remaining).unwrap_or(remaining.len());
+            let accepted = |position: usize| {
+                accepts(suffixes, &remaining[position..])
+                    && path.is_char_boundary(offset + position)
+            };
+
+            // Inline searches try known suffix positions right to left, before the segment end.
+            let inline = if priorities.dynamic_inline { end } else { 0 };
+            let suffixed = (1..=inline).rev().filter(|&position| accepted(position));
+
+            // The segment end comes last, unless the inline pass already tried it.
+            let segment = (end > 0 && !(priorities.dynamic_inline && accepted(end))).then_some(end);
+
+            let name = &child.state.name;
+            let boundaries = suffixed.chain(segment);
+            if let Some(data) = child.search_boundaries(ctx, path, offset, name, boundaries) {
+                return Some(data);
+            }
+        }
+
+        for (index, suffixes) in &priorities.wildcard {
+            let child = &self.wildcard_children[*index];
+            let boundaries = (1..remaining.len())
+                .rev()
+                .filter(|&position| priorities.wildcard_inline || remaining[position] == b'/')
+                .filter(|&position| accepts(suffixes, &remaining[position..]))
+                .filter(|&position| path.is_char_boundary(offset + position));
+
+            let name = &child.state.name;
+            if let Some(data) = child.search_boundaries(ctx, path, offset, name, boundaries) {
+                return Some(data);
+            }
+        }
+
+        let child = self.end_wildcard.as_ref()?;
+        ctx.parameters
+            .capture(&child.name, path, offset..path.len());
+        Some(&child.data)
+    }
+
+    /// Captures each candidate value in turn, until the rest of the path matches.
+    ///
+    /// Boundaries this node already failed from are skipped.
+    fn search_boundaries<'r, 'p>(
+        &'r self,
+        ctx: &mut BuilderSearch<'r, 'p>,
+        path: &'p str,
+        offset: usize,
+        name: &'r str,
+        boundaries: impl Iterator<Item = usize>,
+    ) -> Option<&'r Data> {
+        let node = ptr::from_ref(self).addr();
+
+        for position in boundaries {
+            let boundary = offset + position;
+            if ctx.failed.contains(&(node, boundary)) {
+                continue;
+            }
+
+            ctx.parameters.capture(name, path, offset..boundary);
+
+            if let Some(data) = self.search(ctx, path, boundary) {
+                return Some(data);
+            }
+
+            ctx.parameters.release();
+            ctx.failed.insert((node, boundary));
+        }
+
+        None
+    }
+
+    /// Collects the static text that can follow this parameter, mirroring `Suffixes::compute`.
+    fn suffixes(&self) -> Vec<Vec<u8>> {
+        let mut suffixes = vec![];
+        let mut prefix = vec![];
+
+        for child in &self.static_children {
+            child.walk_suffixes(&mut prefix, &mut suffixes);
+        }
+
+        suffixes
+    }
+}
+
+impl BuilderNode<StaticState> {
+    fn walk_suffixes(&self, prefix: &mut Vec<u8>, suffixes: &mut Vec<Vec<u8>>) {
+        let start = prefix.len();
+        prefix.extend_from_slice(&self.state.prefix);
+
+        if self.data.is_some() || self.has_parameters() {
+            suffixes.push(prefix.clone());
+        }
+
+        for child in &self.static_children {
+            child.walk_suffixes(prefix, suffixes);
+        }
+
+        prefix.truncate(start);
+    }
+}
+
+/// Orders parameter children as the compiler does, by longest suffix then name.
+///
+/// Yields each child's index, alongside its suffixes.
+fn by_priority<S>(children: &[BuilderNode<S>], name: fn(&S) -> &str) -> Vec<(usize, Vec<Vec<u8>>)> {
+    let mut order: Vec<_> = children
+        .iter()
+        .map(BuilderNode::suffixes)
+        .enumerate()
+        .collect();
+
+    order.sort_by(|(a, a_suffixes), (b, b_suffixes)| {
+        let longest = |suffixes: &[Vec<u8>]| suffixes.iter().map(Vec::len).max().unwrap_or(0);
+        longest(b_suffixes)
+            .cmp(&longest(a_suffixes))
+            .then_with(|| name(&children[*a].state).cmp(name(&children[*b].state)))
+    });
+
+    order
+}
+
+/// Whether the input starts with any suffix.
+fn accepts(suffixes: &[Vec<u8>], after: &[u8]) -> bool {
+    suffixes.iter().any(|suffix| after.starts_with(suffix))
+}
@@ -5,31 +5,39 @@ use core::num::NonZeroUsize;
 
 use crate::bounds::Bounds;
 use crate::builder::BuilderNode;
-use crate::node::{Node, SearchMode};
+use crate::node::{Data, Node, SearchMode, Value};
+use crate::parser::{Part, Template};
 use crate::reachable::Reachable;
 use crate::router::Router;
-use crate::state::{DynamicState, RootState, StaticState, WildcardState};
+use crate::state::{DynamicState, EndWildcardState, RootState, StaticState, WildcardState};
 use crate::suffixes::Suffixes;
 
 /// Compiles a builder tree into an optimized tree.
+///
+/// Kept alongside the compiled tree, so later inserts can reuse its needle and parameter IDs.
+#[derive(Clone, Debug)]
 pub(crate) struct Compiler {
     needles: BTreeMap<Box<[u8]>, usize>,
     parameters: usize,
 }
 
 impl Compiler {
-    pub(crate) fn run<T>(builder: BuilderNode<RootState, T>) -> Router<T> {
+    pub(crate) fn run<T>(
+        builder: BuilderNode<RootState>,
+        values: Vec<Value<T>>,
+        duplicates: bool,
+    ) -> Router<T> {
         let mut compiler = Self {
             needles: BTreeMap::new(),
             parameters: 0,
         };
 
         let root = compiler.compile(builder, false);
-        Router::new(root)
+        Router::new(root, values, compiler, duplicates)
     }
 
-    fn compile<S, T>(&mut self, builder: BuilderNode<S, T>, revisitable: bool) -> Node<S, T> {
-        let mut static_children: Vec<Node<StaticState, T>> = builder
+    fn compile<S>(&mut self, builder: BuilderNode<S>, revisitable: bool) -> Node<S> {
+        let mut static_children: Vec<Node<StaticState>> = builder
             .static_children
             .into_iter()
             .map(|child| self.compile(child, revisitable))
@@ -51,7 +59,7 @@ impl Compiler {
         let mut seen = BTreeSet::new();
         let mut prefix = Vec::new();
 
-        let mut dynamic_children: Vec<Node<DynamicState, T>> = builder
+        let mut dynamic_children: Vec<Node<DynamicState>> = builder
             .dynamic_children
             .into_iter()
             .map(|child| self.compile(child, dynamic_revisitable))
@@ -67,7 +75,7 @@ impl Compiler {
             child.reachable = Reachable::compute(child, &mut self.needles);
         }
 
-        let mut wildcard_children: Vec<Node<WildcardState, T>> = builder
+        let mut wildcard_children: Vec<Node<WildcardState>> = builder
             .wildcard_children
             .into_iter()
             .map(|child| self.compile(child, wildcard_revisitable))
@@ -131,4 +139,241 @@ impl Compiler {
         node.bounds = Bounds::compute(&node);
         node
     }
+
+    /// The estimated heap size of the needle table, in bytes.
+    pub(crate) fn heap_size(&self) -> usize {
+        self.needles.len() * size_of::<(Box<[u8]>, usize)>()
+            + self
+                .needles
+                .keys()
+                .map(|needle| needle.len())
+                .sum::<usize>()
+    }
+
+    /// Inserts data into a compiled tree, recomputing only the nodes along the template's path.
+    pub(crate) fn insert(
+        &mut self,
+        root: &mut Node<RootState>,
+        template: &mut Template<'_>,
+        data: Data,
+    ) {
+        self.insert_node(root, template, data, false);
+    }
+
+    fn insert_node<S>(
+        &mut self,
+        node: &mut Node<S>,
+        template: &mut Template<'_>,
+        data: Data,
+        revisitable: bool,
+    ) {
+        match template.parts.pop() {
+            None => node.data = Some(data),
+            Some(Part::Static { prefix }) => {
+                self.insert_static(node, template, data, prefix, revisitable);
+            }
+            Some(Part::Dynamic { name }) => {
+                self.insert_dynamic(node, template, data, name, revisitable);
+            }
+            Some(Part::Wildcard { name }) if template.parts.is_empty() => {
+                node.end_wildcard = Some(EndWildcardState::new(name, data));
+            }
+            Some(Part::Wildcard { name }) => {
+                self.insert_wildcard(node, template, data, name, revisitable);
+            }
+        }
+
+        let dynamic_search = if node.dynamic_children.iter().all(Node::is_segment_only) {
+            SearchMode::Segment
+        } else {
+            SearchMode::Inline
+        };
+
+        let wildcard_search = if node.wildcard_children.iter().all(Node::is_segment_only) {
+            SearchMode::Segment
+        } else {
+            SearchMode::Inline
+        };
+
+        // Switching to inline search makes every dynamic subtree revisitable.
+        if !revisitable
+            && dynamic_search == SearchMode::Inline
+            && node.dynamic_search == SearchMode::Segment
+        {
+            for child in &mut node.dynamic_children {
+                self.revisit(child);
+            }
+        }
+
+        node.dynamic_search = dynamic_search;
+        node.wildcard_search = wildcard_search;
+        node.bounds = Bounds::compute(node);
+    }
+
+    fn insert_static<S>(
+        &mut self,
+        node: &mut Node<S>,
+        template: &mut Template<'_>,
+        data: Data,
+        prefix: &[u8],
+        revisitable: bool,
+    ) {
+        let Some(position) = node
+            .static_children
+            .iter()
+            .position(|child| child.state.prefix[0] == prefix[0])
+        else {
+            let mut child = Node::new(StaticState::new(prefix));
+            self.insert_node(&mut child, template, data, revisitable);
+
+            let mut static_children = core::mem::take(&mut node.static_children).into_vec();
+            static_children.push(child);
+            static_children.sort_by(|a, b| a.state.prefix.cmp(&b.state.prefix));
+            node.static_children = static_children.into_boxed_slice();
+            return;
+        };
+
+        let child = &mut node.static_children[position];
+
+        let common_prefix = prefix
+            .iter()
+            .zip(&child.state.prefix)
+            .take_while(|&(a, b)| a == b)
+            .count();
+
+        if common_prefix >= child.state.prefix.len() {
+            if common_prefix >= prefix.len() {
+                self.insert_node(child, template, data, revisitable);
+            } else {
+                self.insert_static(child, template, data, &prefix[common_prefix..], revisitable);
+                child.bounds = Bounds::compute(child);
+            }
+
+            return;
+        }
+
+        // Split the child, keeping its compiled subtree intact below the common prefix.
+        let head = StaticState::new(&child.state.prefix[..common_prefix]);
+        let mut tail = core::mem::replace(child, Node::new(head));
+        tail.state = StaticState::new(&tail.state.prefix[common_prefix..]);
+
+        if prefix[common_prefix..].is_empty() {
+            child.static_children = Box::new([tail]);
+            self.insert_node(child, template, data, revisitable);
+        } else {
+            let mut branch = Node::new(StaticState::new(&prefix[common_prefix..]));
+            self.insert_node(&mut branch, template, data, revisitable);
+
+            let mut static_children = [tail, branch];
+            static_children.sort_by(|a, b| a.state.prefix.cmp(&b.state.prefix));
+            child.static_children = Box::new(static_children);
+            child.bounds = Bounds::compute(child);
+        }
+    }
+
+    fn insert_dynamic<S>(
+        &mut self,
+        node: &mut Node<S>,
+        template: &mut Template<'_>,
+        data: Data,
+        name: &str,
+        revisitable: bool,
+    ) {
+        let position = node
+            .dynamic_children
+            .iter()
+            .position(|child| *child.state.name == *name)
+            .unwrap_or_else(|| {
+                let mut child = Node::new(DynamicState::new(name));
+                if revisitable {
+                    child.state.id = self.next_id();
+                }
+
+                let mut children = core::mem::take(&mut node.dynamic_children).into_vec();
+                children.push(child);
+                node.dynamic_children = children.into_boxed_slice();
+                node.dynamic_children.len() - 1
+            });
+
+        let inner = revisitable || node.dynamic_search == SearchMode::Inline;
+        let child = &mut node.dynamic_children[position];
+        self.insert_node(child, template, data, inner);
+        self.refresh_parameter(child);
+
+        node.dynamic_children.sort_by(|a, b| {
+            b.suffixes
+                .longest()
+                .cmp(&a.suffixes.longest())
+                .then_with(|| a.state.name.cmp(&b.state.name))
+        });
+    }
+
+    fn insert_wildcard<S>(
+        &mut self,
+        node: &mut Node<S>,
+        template: &mut Template<'_>,
+        data: Data,
+        name: &str,
+        revisitable: bool,
+    ) {
+        let position = node
+            .wildcard_children
+            .iter()
+            .position(|child| *child.state.name == *name)
+            .unwrap_or_else(|| {
+                let mut child = Node::new(WildcardState::new(name));
+                if revisitable {
+                    child.state.id = self.next_id();
+                }
+
+                let mut children = core::mem::take(&mut node.wildcard_children).into_vec();
+                children.push(child);
+                node.wildcard_children = children.into_boxed_slice();
+                node.wildcard_children.len() - 1
+            });
+
+        let child = &mut node.wildcard_children[position];
+        self.insert_node(child, template, data, true);
+        self.refresh_parameter(child);
+
+        node.wildcard_children.sort_by(|a, b| {
+            b.suffixes
+                .longest()
+                .cmp(&a.suffixes.longest())
+                .then_with(|| a.state.name.cmp(&b.state.name))
+        });
+    }
+
+    /// Recomputes the pruning data of a parameter whose subtree changed.
+    fn refresh_parameter<S>(&mut self, child: &mut Node<S>) {
+        child.suffixes = Suffixes::compute(child, &mut Vec::new(), &mut BTreeSet::new());
+        child.reachable = Reachable::compute(child, &mut self.needles);
+    }
+
+    /// Assigns IDs to every parameter below a node that became revisitable.
+    fn revisit<S>(&mut self, node: &mut Node<S>) {
+        for child in &mut node.static_children {
+            self.revisit(child);
+        }
+
+        for child in &mut node.dynamic_children {
+            if child.state.id.is_none() {
+                child.state.id = self.next_id();
+            }
+
+            self.revisit(child);
+        }
+
+        // Wildcard subtrees are always revisitable.
+        for child in &mut node.wildcard_children {
+            if child.state.id.is_none() {
+                child.state.id = self.next_id();
+            }
+        }
+    }
+
+    const fn next_id(&mut self) -> Option<NonZeroUsize> {
+        self.parameters += 1;
+        NonZeroUsize::new(self.parameters)
+    }
 }
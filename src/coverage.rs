@@ -0,0 +1,121 @@
+#[cfg(feature = "coverage")]
+use alloc::vec::Vec;
+#[cfg(feature = "coverage")]
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+#[cfg(feature = "coverage")]
+use crate::node::{Data, Node};
+#[cfg(feature = "coverage")]
+use crate::state::RootState;
+
+/// A search counter, which is zero-sized without the `coverage` feature.
+#[derive(Debug, Default)]
+pub(crate) struct Counter {
+    #[cfg(feature = "coverage")]
+    count: AtomicUsize,
+}
+
+impl Counter {
+    pub(crate) const fn new() -> Self {
+        Self {
+            #[cfg(feature = "coverage")]
+            count: AtomicUsize::new(0),
+        }
+    }
+
+    #[cfg_attr(
+        not(feature = "coverage"),
+        expect(
+            clippy::missing_const_for_fn,
+            clippy::unused_self,
+            reason = "No-op without coverage"
+        )
+    )]
+    #[inline]
+    pub(crate) fn hit(&self) {
+        #[cfg(feature = "coverage")]
+        self.count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    #[cfg(feature = "coverage")]
+    pub(crate) fn get(&self) -> usize {
+        self.count.load(Ordering::Relaxed)
+    }
+}
+
+/// Clones carry over the current count, then count independently.
+impl Clone for Counter {
+    fn clone(&self) -> Self {
+        Self {
+            #[cfg(feature = "coverage")]
+            count: AtomicUsize::new(self.get()),
+        }
+    }
+}
+
+/// Search counts for a compiled [`Router`](crate::Router).
+///
+/// Constructed by [`Router::coverage`](crate::Router::coverage).
+#[cfg(feature = "coverage")]
+#[non_exhaustive]
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Coverage<'r> {
+    /// Each template, including aliases, with the number of searches it matched, in priority order.
+    pub templates: Vec<(&'r str, usize)>,
+
+    /// The number of searches that matched no template.
+    pub misses: usize,
+}
+
+#[cfg(feature = "coverage")]
+impl<'r> Coverage<'r> {
+    pub(crate) fn compute(root: &'r Node<RootState>, misses: &Counter) -> Self {
+        let mut coverage = Self {
+            templates: Vec::new(),
+            misses: misses.get(),
+        };
+
+        coverage.visit(root);
+        coverage
+    }
+
+    fn visit<S>(&mut self, node: &'r Node<S>) {
+        if let Some(data) = &node.data {
+            self.leaf(data);
+        }
+
+        for child in &node.static_children {
+            self.visit(child);
+        }
+
+        for child in &node.dynamic_children {
+            self.visit(child);
+        }
+
+        for child in &node.wildcard_children {
+            self.visit(child);
+        }
+
+        if let Some(child) = &node.end_wildcard {
+            self.leaf(&child.data);
+        }
+    }
+
+    fn leaf(&mut self, data: &'r Data) {
+        self.templates.push((&data.template, data.hits.get()));
+    }
+
+    /// The total number of searches, matched or not.
+    #[must_use]
+    pub fn searches(&self) -> usize {
+        self.templates.iter().map(|&(_, hits)| hits).sum::<usize>() + self.misses
+    }
+
+    /// The templates that no search has matched, in priority order.
+    pub fn unused(&self) -> impl Iterator<Item = &'r str> + '_ {
+        self.templates
+            .iter()
+            .filter(|&&(_, hits)| hits == 0)
+            .map(|&(template, _)| template)
+    }
+}
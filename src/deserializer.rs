@@ -0,0 +1,429 @@
+use core::any::type_name;
+use core::str::{FromStr, Split};
+
+use serde::de::value::StrDeserializer;
+use serde::de::{
+    DeserializeSeed, Deserializer, EnumAccess, IntoDeserializer as _, MapAccess, SeqAccess,
+    VariantAccess, Visitor,
+};
+use serde::forward_to_deserialize_any;
+
+use crate::errors::DeserializeError;
+
+/// Delegates to the value deserializer, when there is exactly one parameter.
+macro_rules! single_value {
+    ($($method:ident)*) => {$(
+        fn $method<V: Visitor<'p>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+            let value = self.single()?;
+            let name = value.name;
+            value.$method(visitor).map_err(|err| err.named(name))
+        }
+    )*};
+}
+
+/// Parses the value via [`FromStr`], then visits the result.
+macro_rules! parse_value {
+    ($($method:ident => $visit:ident)*) => {$(
+        fn $method<V: Visitor<'p>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+            visitor.$visit(self.parse()?)
+        }
+    )*};
+}
+
+/// Deserializes a set of matched parameters.
+///
+/// Maps and structs are keyed by parameter name, while sequences and tuples are positional.
+/// A lone parameter is deserialized as a sequence of its segments instead, while tuples always match parameters by position.
+/// Any other type requires exactly one parameter.
+pub(crate) struct ParametersDeserializer<'a, 'r, 'p> {
+    parameters: &'a [(&'r str, &'p str)],
+}
+
+impl<'a, 'r, 'p> ParametersDeserializer<'a, 'r, 'p> {
+    pub(crate) const fn new(parameters: &'a [(&'r str, &'p str)]) -> Self {
+        Self { parameters }
+    }
+
+    fn single(&self) -> Result<ValueDeserializer<'r, 'p>, DeserializeError> {
+        match self.parameters {
+            [(name, value)] => Ok(ValueDeserializer::new(name, value)),
+            _ => Err(DeserializeError::InvalidLength {
+                expected: 1,
+                found: self.parameters.len(),
+            }),
+        }
+    }
+}
+
+impl<'p> Deserializer<'p> for ParametersDeserializer<'_, '_, 'p> {
+    type Error = DeserializeError;
+
+    single_value! {
+        deserialize_bool
+        deserialize_i8 deserialize_i16 deserialize_i32 deserialize_i64 deserialize_i128
+        deserialize_u8 deserialize_u16 deserialize_u32 deserialize_u64 deserialize_u128
+        deserialize_f32 deserialize_f64
+        deserialize_char deserialize_str deserialize_string
+        deserialize_bytes deserialize_byte_buf
+        deserialize_identifier
+    }
+
+    fn deserialize_any<V: Visitor<'p>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.deserialize_map(visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'p>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        if self.parameters.is_empty() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'p>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'p>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'p>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'p>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        if let Ok(value) = self.single() {
+            let name = value.name;
+            return value
+                .deserialize_seq(visitor)
+                .map_err(|err| err.named(name));
+        }
+
+        visitor.visit_seq(ParametersAccess::new(self.parameters))
+    }
+
+    fn deserialize_tuple<V: Visitor<'p>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        if self.parameters.len() != len {
+            return Err(DeserializeError::InvalidLength {
+                expected: len,
+                found: self.parameters.len(),
+            });
+        }
+
+        visitor.visit_seq(ParametersAccess::new(self.parameters))
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'p>>(
+        self,
+        _name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_tuple(len, visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'p>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_map(ParametersAccess::new(self.parameters))
+    }
+
+    fn deserialize_struct<V: Visitor<'p>>(
+        self,
+        _name: &'static str,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_map(visitor)
+    }
+
+    fn deserialize_enum<V: Visitor<'p>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        let value = self.single()?;
+        let parameter = value.name;
+        value
+            .deserialize_enum(name, variants, visitor)
+            .map_err(|err| err.named(parameter))
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'p>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_unit()
+    }
+}
+
+/// Walks the parameters, as either a map or a sequence.
+struct ParametersAccess<'a, 'r, 'p> {
+    parameters: core::slice::Iter<'a, (&'r str, &'p str)>,
+    value: Option<(&'r str, &'p str)>,
+}
+
+impl<'a, 'r, 'p> ParametersAccess<'a, 'r, 'p> {
+    fn new(parameters: &'a [(&'r str, &'p str)]) -> Self {
+        Self {
+            parameters: parameters.iter(),
+            value: None,
+        }
+    }
+}
+
+impl<'p> MapAccess<'p> for ParametersAccess<'_, '_, 'p> {
+    type Error = DeserializeError;
+
+    fn next_key_seed<K: DeserializeSeed<'p>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        let Some(&(name, value)) = self.parameters.next() else {
+            return Ok(None);
+        };
+
+        self.value = Some((name, value));
+
+        let key: StrDeserializer<'_, DeserializeError> = name.into_deserializer();
+        seed.deserialize(key).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'p>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Self::Error> {
+        let Some((name, value)) = self.value.take() else {
+            return Err(serde::de::Error::custom("value requested before key"));
+        };
+
+        seed.deserialize(ValueDeserializer::new(name, value))
+            .map_err(|err| err.named(name))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.parameters.len())
+    }
+}
+
+impl<'p> SeqAccess<'p> for ParametersAccess<'_, '_, 'p> {
+    type Error = DeserializeError;
+
+    fn next_element_seed<T: DeserializeSeed<'p>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Self::Error> {
+        let Some(&(name, value)) = self.parameters.next() else {
+            return Ok(None);
+        };
+
+        seed.deserialize(ValueDeserializer::new(name, value))
+            .map(Some)
+            .map_err(|err| err.named(name))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.parameters.len())
+    }
+}
+
+/// Deserializes a single parameter value.
+///
+/// Sequences split the value into its `/` delimited segments, for use with wildcards.
+struct ValueDeserializer<'r, 'p> {
+    name: &'r str,
+    value: &'p str,
+}
+
+impl<'r, 'p> ValueDeserializer<'r, 'p> {
+    const fn new(name: &'r str, value: &'p str) -> Self {
+        Self { name, value }
+    }
+
+    fn parse<F: FromStr>(&self) -> Result<F, DeserializeError> {
+        self.value
+            .parse()
+            .map_err(|_err| DeserializeError::InvalidValue {
+                name: self.name.into(),
+                value: self.value.into(),
+                expected: type_name::<F>(),
+            })
+    }
+}
+
+impl<'p> Deserializer<'p> for ValueDeserializer<'_, 'p> {
+    type Error = DeserializeError;
+
+    parse_value! {
+        deserialize_bool => visit_bool
+        deserialize_i8 => visit_i8
+        deserialize_i16 => visit_i16
+        deserialize_i32 => visit_i32
+        deserialize_i64 => visit_i64
+        deserialize_i128 => visit_i128
+        deserialize_u8 => visit_u8
+        deserialize_u16 => visit_u16
+        deserialize_u32 => visit_u32
+        deserialize_u64 => visit_u64
+        deserialize_u128 => visit_u128
+        deserialize_f32 => visit_f32
+        deserialize_f64 => visit_f64
+        deserialize_char => visit_char
+    }
+
+    forward_to_deserialize_any! {
+        <V: Visitor<'p>>
+        str string identifier map struct ignored_any
+    }
+
+    fn deserialize_any<V: Visitor<'p>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_borrowed_str(self.value)
+    }
+
+    fn deserialize_bytes<V: Visitor<'p>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_borrowed_bytes(self.value.as_bytes())
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'p>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.deserialize_bytes(visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'p>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_unit<V: Visitor<'p>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'p>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'p>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'p>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_seq(SegmentsAccess {
+            name: self.name,
+            segments: self.value.split('/'),
+        })
+    }
+
+    fn deserialize_tuple<V: Visitor<'p>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        let segments = self.value.split('/').count();
+        if segments != len {
+            return Err(serde::de::Error::invalid_length(segments, &visitor));
+        }
+
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'p>>(
+        self,
+        _name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_tuple(len, visitor)
+    }
+
+    fn deserialize_enum<V: Visitor<'p>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_enum(self)
+    }
+}
+
+impl<'p> EnumAccess<'p> for ValueDeserializer<'_, 'p> {
+    type Error = DeserializeError;
+    type Variant = UnitVariant;
+
+    fn variant_seed<V: DeserializeSeed<'p>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Self::Variant), Self::Error> {
+        seed.deserialize(self).map(|variant| (variant, UnitVariant))
+    }
+}
+
+/// Only unit variants can be represented by a parameter value.
+struct UnitVariant;
+
+impl<'p> VariantAccess<'p> for UnitVariant {
+    type Error = DeserializeError;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'p>>(
+        self,
+        _seed: T,
+    ) -> Result<T::Value, Self::Error> {
+        Err(serde::de::Error::custom("expected a unit variant"))
+    }
+
+    fn tuple_variant<V: Visitor<'p>>(
+        self,
+        _len: usize,
+        _visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        Err(serde::de::Error::custom("expected a unit variant"))
+    }
+
+    fn struct_variant<V: Visitor<'p>>(
+        self,
+        _fields: &'static [&'static str],
+        _visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        Err(serde::de::Error::custom("expected a unit variant"))
+    }
+}
+
+/// Walks the `/` delimited segments of a wildcard value.
+struct SegmentsAccess<'r, 'p> {
+    name: &'r str,
+    segments: Split<'p, char>,
+}
+
+impl<'p> SeqAccess<'p> for SegmentsAccess<'_, 'p> {
+    type Error = DeserializeError;
+
+    fn next_element_seed<T: DeserializeSeed<'p>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Self::Error> {
+        let Some(segment) = self.segments.next() else {
+            return Ok(None);
+        };
+
+        seed.deserialize(ValueDeserializer::new(self.name, segment))
+            .map(Some)
+    }
+}
@@ -0,0 +1,177 @@
+use alloc::vec::Vec;
+use core::panic::Location;
+
+use crate::builder::BuilderNode;
+use crate::coverage::Counter;
+use crate::node::{Data, Value};
+use crate::parser::Template;
+use crate::state::RootState;
+
+/// A view into a single template of a [`RouterBuilder`](crate::RouterBuilder), which may be vacant or occupied.
+///
+/// Constructed by [`RouterBuilder::entry`](crate::RouterBuilder::entry).
+#[derive(Debug)]
+pub enum Entry<'a, T> {
+    /// The template has been inserted.
+    Occupied(OccupiedEntry<'a, T>),
+
+    /// The template has not been inserted.
+    Vacant(VacantEntry<'a, T>),
+}
+
+impl<'a, T> Entry<'a, T> {
+    pub(crate) const fn occupied(data: &'a mut Data, value: &'a mut Value<T>) -> Self {
+        Self::Occupied(OccupiedEntry { data, value })
+    }
+
+    pub(crate) const fn vacant(
+        root: &'a mut BuilderNode<RootState>,
+        values: &'a mut Vec<Value<T>>,
+        template: &'a str,
+        parsed: Template<'a>,
+    ) -> Self {
+        Self::Vacant(VacantEntry {
+            root,
+            values,
+            template,
+            parsed,
+        })
+    }
+
+    /// The template of this entry.
+    #[must_use]
+    pub fn template(&self) -> &str {
+        match self {
+            Self::Occupied(entry) => entry.template(),
+            Self::Vacant(entry) => entry.template(),
+        }
+    }
+
+    /// Inserts the data if vacant, returning a mutable reference to the stored data.
+    #[track_caller]
+    pub fn or_insert(self, data: T) -> &'a mut T {
+        match self {
+            Self::Occupied(entry) => entry.into_mut(),
+            Self::Vacant(entry) => entry.insert(data),
+        }
+    }
+
+    /// Inserts the result of the function if vacant, returning a mutable reference to the stored data.
+    #[track_caller]
+    pub fn or_insert_with<F: FnOnce() -> T>(self, default: F) -> &'a mut T {
+        match self {
+            Self::Occupied(entry) => entry.into_mut(),
+            Self::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    /// Modifies the stored data if occupied.
+    #[must_use]
+    pub fn and_modify<F: FnOnce(&mut T)>(self, modify: F) -> Self {
+        match self {
+            Self::Occupied(mut entry) => {
+                modify(entry.get_mut());
+                Self::Occupied(entry)
+            }
+            Self::Vacant(entry) => Self::Vacant(entry),
+        }
+    }
+}
+
+impl<'a, T: Default> Entry<'a, T> {
+    /// Inserts the default value if vacant, returning a mutable reference to the stored data.
+    #[track_caller]
+    pub fn or_default(self) -> &'a mut T {
+        self.or_insert_with(T::default)
+    }
+}
+
+/// An inserted template.
+#[derive(Debug)]
+pub struct OccupiedEntry<'a, T> {
+    data: &'a mut Data,
+    value: &'a mut Value<T>,
+}
+
+impl<'a, T> OccupiedEntry<'a, T> {
+    /// The template of this entry.
+    #[must_use]
+    pub fn template(&self) -> &str {
+        &self.data.template
+    }
+
+    /// The stored data.
+    #[must_use]
+    pub const fn get(&self) -> &T {
+        &self.value.data
+    }
+
+    /// The stored data, mutably.
+    pub const fn get_mut(&mut self) -> &mut T {
+        &mut self.value.data
+    }
+
+    /// Converts the entry into a mutable reference to the stored data.
+    #[must_use]
+    pub const fn into_mut(self) -> &'a mut T {
+        &mut self.value.data
+    }
+
+    /// Replaces the stored data, returning the previous data.
+    ///
+    /// Any duplicates of the previous data are discarded.
+    /// The caller's location is recorded, replacing the original insertion location.
+    #[track_caller]
+    pub fn insert(&mut self, data: T) -> T {
+        self.data.location = Location::caller();
+        self.value.duplicates = None;
+        core::mem::replace(&mut self.value.data, data)
+    }
+}
+
+/// A template that has not been inserted.
+#[derive(Debug)]
+pub struct VacantEntry<'a, T> {
+    root: &'a mut BuilderNode<RootState>,
+    values: &'a mut Vec<Value<T>>,
+    template: &'a str,
+    parsed: Template<'a>,
+}
+
+impl<'a, T> VacantEntry<'a, T> {
+    /// The template of this entry.
+    #[must_use]
+    pub const fn template(&self) -> &str {
+        self.template
+    }
+
+    /// Inserts the data, returning a mutable reference to it.
+    #[track_caller]
+    pub fn insert(self, data: T) -> &'a mut T {
+        let Self {
+            root,
+            values,
+            template,
+            mut parsed,
+        } = self;
+
+        let slot = values.len();
+        root.insert(
+            &mut parsed,
+            Data {
+                slot,
+                template: template.into(),
+                canonical: None,
+                location: Location::caller(),
+                hits: Counter::new(),
+            },
+        );
+
+        values.push(Value {
+            data,
+            duplicates: None,
+        });
+
+        &mut values[slot].data
+    }
+}
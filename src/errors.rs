@@ -1,6 +1,9 @@
 use alloc::string::String;
+use alloc::vec::Vec;
 use core::error::Error;
 use core::fmt;
+use core::ops::Range;
+use core::panic::Location;
 
 /// An error that occurred while inserting a template.
 #[non_exhaustive]
@@ -10,51 +13,279 @@ pub enum InsertError {
     Empty,
 
     /// The template does not start with `/`.
-    MissingSlash,
+    MissingSlash {
+        /// The rejected template.
+        template: String,
+        /// The byte range of the offending character.
+        span: Range<usize>,
+    },
 
     /// An unbalanced `<` or `>` was found in the template.
-    UnbalancedAngle,
+    UnbalancedAngle {
+        /// The rejected template.
+        template: String,
+        /// The byte range of the unbalanced bracket.
+        span: Range<usize>,
+    },
 
     /// A parameter name is empty.
-    EmptyParameter,
+    EmptyParameter {
+        /// The rejected template.
+        template: String,
+        /// The byte range of the empty parameter.
+        span: Range<usize>,
+    },
 
     /// A parameter name contains invalid characters.
     InvalidParameter {
         /// The invalid parameter name.
         name: String,
+        /// The rejected template.
+        template: String,
+        /// The byte range of the invalid parameter.
+        span: Range<usize>,
     },
 
     /// A parameter name appears more than once in the template.
     DuplicateParameter {
         /// The duplicated parameter name.
         name: String,
+        /// The rejected template.
+        template: String,
+        /// The byte range of the repeated parameter.
+        span: Range<usize>,
     },
 
     /// Parameters are touching without a static separator.
-    TouchingParameters,
+    TouchingParameters {
+        /// The rejected template.
+        template: String,
+        /// The byte range of the touching parameter.
+        span: Range<usize>,
+    },
 
     /// The template conflicts with an already inserted template.
     Conflict {
         /// The existing template that conflicts.
         existing: String,
+
+        /// An example path matched by both templates.
+        witness: String,
+
+        /// Where the existing template was inserted.
+        location: &'static Location<'static>,
+    },
+
+    /// The template to alias has not been inserted.
+    UnknownTemplate {
+        /// The missing template.
+        template: String,
     },
+
+    /// An alias declares different parameters than the template it aliases.
+    MismatchedParameters {
+        /// The alias template.
+        alias: String,
+        /// The aliased template.
+        canonical: String,
+    },
+}
+
+impl InsertError {
+    /// The rejected template and the byte range of the offending part, for parse errors.
+    #[must_use]
+    pub fn span(&self) -> Option<(&str, Range<usize>)> {
+        match self {
+            Self::MissingSlash { template, span }
+            | Self::UnbalancedAngle { template, span }
+            | Self::EmptyParameter { template, span }
+            | Self::InvalidParameter { template, span, .. }
+            | Self::DuplicateParameter { template, span, .. }
+            | Self::TouchingParameters { template, span } => Some((template, span.clone())),
+            Self::Empty
+            | Self::Conflict { .. }
+            | Self::UnknownTemplate { .. }
+            | Self::MismatchedParameters { .. } => None,
+        }
+    }
 }
 
 impl Error for InsertError {}
 
+/// The alternate form (`{:#}`) underlines the offending part of the template.
 impl fmt::Display for InsertError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::Empty => write!(f, "empty template"),
-            Self::MissingSlash => write!(f, "missing leading slash"),
-            Self::UnbalancedAngle => write!(f, "unbalanced angle bracket"),
-            Self::EmptyParameter => write!(f, "empty parameter name"),
-            Self::InvalidParameter { name } => write!(f, "invalid parameter name `{name}`"),
-            Self::DuplicateParameter { name } => write!(f, "duplicate parameter name `{name}`"),
-            Self::TouchingParameters => {
-                write!(f, "parameters must be separated by a static character")
+            Self::Empty => write!(f, "empty template")?,
+            Self::MissingSlash { .. } => write!(f, "missing leading slash")?,
+            Self::UnbalancedAngle { .. } => write!(f, "unbalanced angle bracket")?,
+            Self::EmptyParameter { .. } => write!(f, "empty parameter name")?,
+            Self::InvalidParameter { name, .. } => write!(f, "invalid parameter name `{name}`")?,
+            Self::DuplicateParameter { name, .. } => {
+                write!(f, "duplicate parameter name `{name}`")?;
+            }
+            Self::TouchingParameters { .. } => {
+                write!(f, "parameters must be separated by a static character")?;
+            }
+            Self::Conflict {
+                existing,
+                witness,
+                location,
+            } => {
+                write!(
+                    f,
+                    "conflicts with `{existing}` (inserted at {}:{}), both match `{witness}`",
+                    location.file(),
+                    location.line(),
+                )?;
+            }
+            Self::UnknownTemplate { template } => write!(f, "unknown template `{template}`")?,
+            Self::MismatchedParameters { alias, canonical } => {
+                write!(
+                    f,
+                    "alias `{alias}` has different parameters than `{canonical}`"
+                )?;
+            }
+        }
+
+        if let Some((template, span)) = self.span().filter(|_| f.alternate()) {
+            let padding = template
+                .get(..span.start)
+                .map_or(0, |text| text.chars().count());
+            let width = template.get(span).map_or(1, |text| text.chars().count());
+            write!(f, "\n{template}\n{:padding$}{:^<width$}", "", "")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A template that failed to insert, during a bulk insert.
+#[derive(Clone, PartialEq, Debug)]
+pub struct InsertFailure {
+    /// The position of the template in the input.
+    pub index: usize,
+
+    /// The rejected template.
+    pub template: String,
+
+    /// The reason the template was rejected.
+    pub error: InsertError,
+}
+
+impl fmt::Display for InsertFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{} `{}`: {}", self.index, self.template, self.error)
+    }
+}
+
+/// Every error that occurred while inserting multiple templates.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ExtendError {
+    /// Each rejected template, in input order.
+    pub failures: Vec<InsertFailure>,
+}
+
+impl Error for ExtendError {}
+
+impl fmt::Display for ExtendError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "failed to insert {} template(s)", self.failures.len())?;
+        for failure in &self.failures {
+            write!(f, "\n- {failure}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// An error that occurred while deserializing parameters.
+#[cfg(feature = "serde")]
+#[non_exhaustive]
+#[derive(Clone, PartialEq, Debug)]
+pub enum DeserializeError {
+    /// A parameter value could not be parsed into the expected type.
+    InvalidValue {
+        /// The parameter name.
+        name: String,
+        /// The unparsable value.
+        value: String,
+        /// The expected type.
+        expected: &'static str,
+    },
+
+    /// The number of parameters did not match the expected type.
+    InvalidLength {
+        /// The expected number of parameters.
+        expected: usize,
+        /// The actual number of parameters.
+        found: usize,
+    },
+
+    /// A custom error raised by the deserialized type.
+    Custom {
+        /// The parameter being deserialized, if any.
+        name: Option<String>,
+        /// The error message.
+        message: String,
+    },
+}
+
+#[cfg(feature = "serde")]
+impl DeserializeError {
+    /// Attributes a custom error to the parameter that caused it.
+    pub(crate) fn named(self, parameter: &str) -> Self {
+        match self {
+            Self::Custom {
+                name: None,
+                message,
+            } => Self::Custom {
+                name: Some(parameter.into()),
+                message,
+            },
+            error @ (Self::InvalidValue { .. }
+            | Self::InvalidLength { .. }
+            | Self::Custom { .. }) => error,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Error for DeserializeError {}
+
+#[cfg(feature = "serde")]
+impl serde::de::Error for DeserializeError {
+    fn custom<M: fmt::Display>(msg: M) -> Self {
+        Self::Custom {
+            name: None,
+            message: alloc::format!("{msg}"),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl fmt::Display for DeserializeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidValue {
+                name,
+                value,
+                expected,
+            } => write!(
+                f,
+                "invalid value `{value}` for parameter `{name}`, expected {expected}"
+            ),
+            Self::InvalidLength { expected, found } => {
+                write!(f, "expected {expected} parameter(s), found {found}")
             }
-            Self::Conflict { existing } => write!(f, "conflicts with `{existing}`"),
+            Self::Custom {
+                name: Some(name),
+                message,
+            } => write!(f, "parameter `{name}`: {message}"),
+            Self::Custom {
+                name: None,
+                message,
+            } => write!(f, "{message}"),
         }
     }
 }
@@ -0,0 +1,282 @@
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::visitor::NodeKind;
+
+/// A single step of an explained search.
+#[non_exhaustive]
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum Step {
+    /// Descended into a static child, after matching its prefix.
+    Enter {
+        /// The static prefix.
+        prefix: String,
+        /// The path offset after the prefix.
+        offset: usize,
+    },
+
+    /// Descended into a parameter child, capturing a value.
+    Capture {
+        /// The parameter, as written in the template.
+        parameter: String,
+        /// The captured value.
+        value: String,
+        /// Whether the value ends where a known suffix of the child starts.
+        suffix: bool,
+        /// The kind of parameter.
+        kind: NodeKind,
+    },
+
+    /// Left the current node without finding a match.
+    Backtrack,
+
+    /// The current node was pruned, since the remaining path length is outside its bounds.
+    OutOfBounds {
+        /// The remaining path bytes.
+        remaining: usize,
+        /// The minimum remaining path bytes to reach any match.
+        shortest: usize,
+        /// The maximum remaining path bytes that could still match.
+        longest: usize,
+    },
+
+    /// The path ended at the current node, which has no template.
+    NoTemplate,
+
+    /// A parameter child was skipped, since the remaining path is too short for any of its templates.
+    TooShort {
+        /// The parameter, as written in the template.
+        parameter: String,
+    },
+
+    /// A parameter child was skipped, since the remaining path lacks the static text its templates require.
+    Unreachable {
+        /// The parameter, as written in the template.
+        parameter: String,
+    },
+
+    /// A parameter child was skipped, since it would capture an empty value.
+    Empty {
+        /// The parameter, as written in the template.
+        parameter: String,
+    },
+
+    /// A wildcard value was skipped, since the rest of the path doesn't start with any of the wildcard's suffixes.
+    NoSuffix {
+        /// The parameter, as written in the template.
+        parameter: String,
+        /// The skipped value.
+        value: String,
+    },
+
+    /// A parameter's values were limited, since an earlier visit already ruled out longer values.
+    Memoized {
+        /// The parameter, as written in the template.
+        parameter: String,
+        /// The longest value still worth trying, in bytes.
+        limit: usize,
+    },
+
+    /// The search found a template.
+    Matched {
+        /// The matched template.
+        template: String,
+    },
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Enter { prefix, offset } => write!(f, "{prefix:?} to offset {offset}"),
+            Self::Capture {
+                parameter,
+                value,
+                suffix,
+                ..
+            } => {
+                write!(f, "{parameter} = {value:?}")?;
+                if *suffix {
+                    write!(f, " (before a suffix)")?;
+                }
+
+                Ok(())
+            }
+            Self::Backtrack => write!(f, "backtrack"),
+            Self::OutOfBounds {
+                remaining,
+                shortest,
+                longest,
+            } => {
+                write!(f, "pruned by bounds, {remaining} bytes remain but ")?;
+                if *longest == usize::MAX {
+                    write!(f, "at least {shortest} are needed")
+                } else {
+                    write!(f, "{shortest} to {longest} are needed")
+                }
+            }
+            Self::NoTemplate => write!(f, "path ends here, but no template does"),
+            Self::TooShort { parameter } => write!(f, "skipped {parameter}, too short"),
+            Self::Unreachable { parameter } => write!(f, "skipped {parameter}, unreachable"),
+            Self::Empty { parameter } => write!(f, "skipped {parameter}, empty value"),
+            Self::NoSuffix { parameter, value } => {
+                write!(f, "skipped {parameter} = {value:?}, no suffix follows")
+            }
+            Self::Memoized { parameter, limit } => {
+                write!(
+                    f,
+                    "{parameter} limited to {limit} bytes by an earlier visit"
+                )
+            }
+            Self::Matched { template } => write!(f, "matched `{template}`"),
+        }
+    }
+}
+
+/// The branch of the search that decided the match.
+///
+/// The shallowest point on the path to the matched template where earlier candidates were tried and failed.
+#[non_exhaustive]
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub struct Decision {
+    /// The kind of the winning child, which sets its priority.
+    pub kind: NodeKind,
+
+    /// The path offset the winning child starts at.
+    pub offset: usize,
+
+    /// The number of earlier candidates that failed at this point.
+    pub rejected: usize,
+}
+
+/// A step-by-step trace of a search.
+///
+/// Constructed by [`Router::explain`](crate::Router::explain).
+#[non_exhaustive]
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Explanation {
+    /// The searched path.
+    pub path: String,
+
+    /// The matched template, if any.
+    pub template: Option<String>,
+
+    /// Where the match was decided, unless no other candidate was tried.
+    pub decision: Option<Decision>,
+
+    /// Every step taken, in order.
+    pub steps: Vec<Step>,
+}
+
+impl Explanation {
+    pub(crate) fn new(path: &str, template: Option<String>, steps: Vec<Step>) -> Self {
+        let decision = template.as_ref().and_then(|_| decide(&steps));
+        Self {
+            path: path.into(),
+            template,
+            decision,
+            steps,
+        }
+    }
+}
+
+/// A node on the current search path.
+struct Frame {
+    kind: NodeKind,
+    offset: usize,
+    rejected: usize,
+}
+
+/// Finds the shallowest node on the winning path where earlier candidates failed.
+fn decide(steps: &[Step]) -> Option<Decision> {
+    let mut frames = vec![Frame {
+        kind: NodeKind::Root,
+        offset: 0,
+        rejected: 0,
+    }];
+
+    for step in steps {
+        match step {
+            Step::Enter { offset, .. } => frames.push(Frame {
+                kind: NodeKind::Static,
+                offset: *offset,
+                rejected: 0,
+            }),
+            Step::Capture { value, kind, .. } => {
+                let start = frames.last().map_or(0, |frame| frame.offset);
+                frames.push(Frame {
+                    kind: *kind,
+                    offset: start + value.len(),
+                    rejected: 0,
+                });
+            }
+            Step::Backtrack => {
+                frames.pop();
+                if let Some(frame) = frames.last_mut() {
+                    frame.rejected += 1;
+                }
+            }
+            Step::TooShort { .. }
+            | Step::Unreachable { .. }
+            | Step::Empty { .. }
+            | Step::NoSuffix { .. } => {
+                if let Some(frame) = frames.last_mut() {
+                    frame.rejected += 1;
+                }
+            }
+            Step::OutOfBounds { .. }
+            | Step::NoTemplate
+            | Step::Memoized { .. }
+            | Step::Matched { .. } => {}
+        }
+    }
+
+    frames.windows(2).find_map(|pair| match pair {
+        [parent, child] if parent.rejected > 0 => Some(Decision {
+            kind: child.kind,
+            offset: parent.offset,
+            rejected: parent.rejected,
+        }),
+        _ => None,
+    })
+}
+
+/// Renders the steps as an indented tree, following the descent into each child.
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.template, &self.decision) {
+            (Some(template), Some(decision)) => writeln!(
+                f,
+                "`{}` matched `{template}`, decided by the {} child at offset {}, after {} earlier candidate(s) failed",
+                self.path, decision.kind, decision.offset, decision.rejected
+            )?,
+            (Some(template), None) => writeln!(
+                f,
+                "`{}` matched `{template}`, without trying any other candidate",
+                self.path
+            )?,
+            (None, _) => writeln!(f, "`{}` matched nothing", self.path)?,
+        }
+
+        let mut depth = 0;
+        for step in &self.steps {
+            writeln!(f, "{:indent$}{step}", "", indent = depth * 2)?;
+
+            match step {
+                Step::Enter { .. } | Step::Capture { .. } => depth += 1,
+                Step::Backtrack => depth = depth.saturating_sub(1),
+                Step::OutOfBounds { .. }
+                | Step::NoTemplate
+                | Step::TooShort { .. }
+                | Step::Unreachable { .. }
+                | Step::Empty { .. }
+                | Step::NoSuffix { .. }
+                | Step::Memoized { .. }
+                | Step::Matched { .. } => {}
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,180 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::node::SearchMode;
+use crate::visitor::{NodeKind, NodeRef, Visitor};
+
+/// A node of the flattened search tree.
+struct Vertex<'r> {
+    kind: NodeKind,
+    prefix: Option<&'r [u8]>,
+    name: Option<&'r str>,
+    template: Option<&'r str>,
+}
+
+/// A parent-child link, annotated with the search mode used for parameter children.
+struct Edge {
+    from: usize,
+    to: usize,
+    mode: Option<SearchMode>,
+}
+
+/// A graph description language.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Format {
+    Dot,
+    Mermaid,
+}
+
+/// The search tree flattened into vertices and edges, in priority order.
+pub(crate) struct Graph<'r> {
+    format: Format,
+    vertices: Vec<Vertex<'r>>,
+    edges: Vec<Edge>,
+    /// The vertices being visited, alongside their search modes.
+    parents: Vec<(usize, Option<SearchMode>, Option<SearchMode>)>,
+}
+
+impl<'r, T> Visitor<'r, T> for Graph<'r> {
+    fn enter(&mut self, node: NodeRef<'r, T>) -> bool {
+        let id = self.vertices.len();
+        if let Some(&(from, dynamic, wildcard)) = self.parents.last() {
+            let mode = match node.kind() {
+                NodeKind::Dynamic => dynamic,
+                NodeKind::Wildcard => wildcard,
+                NodeKind::Root | NodeKind::Static | NodeKind::EndWildcard => None,
+            };
+
+            self.edges.push(Edge { from, to: id, mode });
+        }
+
+        self.vertices.push(Vertex {
+            kind: node.kind(),
+            prefix: node.prefix(),
+            name: node.name(),
+            template: node.template(),
+        });
+
+        self.parents
+            .push((id, node.dynamic_search(), node.wildcard_search()));
+
+        true
+    }
+
+    fn leave(&mut self, _node: NodeRef<'r, T>) {
+        self.parents.pop();
+    }
+}
+
+impl Graph<'_> {
+    pub(crate) const fn new(format: Format) -> Self {
+        Self {
+            format,
+            vertices: Vec::new(),
+            edges: Vec::new(),
+            parents: Vec::new(),
+        }
+    }
+
+    /// Renders the graph in Graphviz DOT format.
+    fn dot(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "digraph router {{")?;
+        writeln!(f, "    node [shape=box];")?;
+
+        for (id, vertex) in self.vertices.iter().enumerate() {
+            let heading = escape_dot(&vertex.heading());
+            match vertex.template {
+                Some(template) => {
+                    let template = escape_dot(template);
+                    writeln!(
+                        f,
+                        "    n{id} [label=\"{heading}\\n{template}\", peripheries=2];"
+                    )?;
+                }
+                None => writeln!(f, "    n{id} [label=\"{heading}\"];")?,
+            }
+        }
+
+        for Edge { from, to, mode } in &self.edges {
+            match mode {
+                Some(mode) => writeln!(f, "    n{from} -> n{to} [label=\"{mode}\"];")?,
+                None => writeln!(f, "    n{from} -> n{to};")?,
+            }
+        }
+
+        writeln!(f, "}}")
+    }
+
+    /// Renders the graph as a Mermaid flowchart.
+    fn mermaid(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "flowchart TD")?;
+
+        for (id, vertex) in self.vertices.iter().enumerate() {
+            let heading = escape_mermaid(&vertex.heading());
+            match vertex.template {
+                Some(template) => {
+                    let template = escape_mermaid(template);
+                    writeln!(f, "    n{id}([\"{heading}<br/>{template}\"])")?;
+                }
+                None => writeln!(f, "    n{id}[\"{heading}\"]")?,
+            }
+        }
+
+        for Edge { from, to, mode } in &self.edges {
+            match mode {
+                Some(mode) => writeln!(f, "    n{from} -->|{mode}| n{to}")?,
+                None => writeln!(f, "    n{from} --> n{to}")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Graph<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.format {
+            Format::Dot => self.dot(f),
+            Format::Mermaid => self.mermaid(f),
+        }
+    }
+}
+
+impl Vertex<'_> {
+    /// The node kind, followed by its prefix or parameter.
+    fn heading(&self) -> String {
+        let kind = self.kind;
+        let name = self.name.unwrap_or_default();
+        match kind {
+            NodeKind::Root => format!("{kind}"),
+            NodeKind::Static => {
+                let prefix = String::from_utf8_lossy(self.prefix.unwrap_or_default());
+                format!("{kind}: {prefix}")
+            }
+            NodeKind::Dynamic => format!("{kind}: <{name}>"),
+            NodeKind::Wildcard | NodeKind::EndWildcard => format!("{kind}: <*{name}>"),
+        }
+    }
+}
+
+fn escape_dot(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn escape_mermaid(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for char in text.chars() {
+        match char {
+            '#' => escaped.push_str("#35;"),
+            '"' => escaped.push_str("#quot;"),
+            '<' => escaped.push_str("#lt;"),
+            '>' => escaped.push_str("#gt;"),
+            '&' => escaped.push_str("#amp;"),
+            _ => escaped.push(char),
+        }
+    }
+
+    escaped
+}
@@ -111,26 +111,69 @@
 //! ## Display
 //!
 //! The router can be printed as a tree, via a [`Display`](core::fmt::Display) implementation.
+//!
+//! For larger route tables, [`Router::to_dot`] and [`Router::to_mermaid`] render the tree for Graphviz and Mermaid.
+//! For custom tooling, [`Router::root`] and [`Visitor`] give read-only access to the compiled tree.
+//!
+//! ## Features
+//!
+//! - `coverage`: Count searches per template, via `Router::coverage`.
+//! - `explain`: Trace every step of a search, via `Router::explain`.
+//! - `serde`: Deserialize matched parameters into typed structs, via `Match::deserialize`.
+//! - `std`: Share a hot-swappable router between threads, via `SharedRouter`.
+//! - `tracing`: Emit [`tracing`](https://docs.rs/tracing) events for inserts, conflicts and searches, and a `build` span around compilation, timed when `std` is enabled.
 
 #![no_std]
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 #[cfg(doctest)]
 #[doc = include_str!("../README.md")]
 mod readme_doctests {}
 
+mod analysis;
+pub use analysis::{Ambiguity, DeadRoute};
 mod bounds;
+pub use bounds::Bounds;
 mod builder;
 pub use builder::RouterBuilder;
 mod compiler;
+mod coverage;
+#[cfg(feature = "coverage")]
+pub use coverage::Coverage;
+#[cfg(feature = "serde")]
+mod deserializer;
+mod entry;
+pub use entry::{Entry, OccupiedEntry, VacantEntry};
 mod errors;
-pub use errors::InsertError;
+#[cfg(feature = "serde")]
+pub use errors::DeserializeError;
+pub use errors::{ExtendError, InsertError, InsertFailure};
+#[cfg(feature = "explain")]
+mod explain;
+#[cfg(feature = "explain")]
+pub use explain::{Decision, Explanation, Step};
+mod export;
+mod lint;
+pub use lint::{Lint, LintConfig, LintRule};
 mod needle;
 mod node;
+pub use node::SearchMode;
+mod parameters;
+pub use parameters::{ParameterSink, Parameters};
 mod parser;
 mod reachable;
 mod router;
-pub use router::{Match, Router};
+pub use router::{Match, Route, Router, SearchScratch};
+#[cfg(feature = "std")]
+mod shared;
+#[cfg(feature = "std")]
+pub use shared::{SharedRouter, Snapshot};
 mod state;
+mod stats;
+pub use stats::RouterStats;
 mod storage;
 mod suffixes;
+mod visitor;
+pub use visitor::{NodeKind, NodeRef, Visitor};
@@ -0,0 +1,307 @@
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::{String, ToString as _};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::parameters::INLINE_PARAMETERS;
+use crate::parser::{Part, Template};
+
+/// A single lint rule.
+#[non_exhaustive]
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub enum LintRule {
+    /// Templates that differ only by a trailing slash.
+    TrailingSlash,
+
+    /// Parameter names that aren't `snake_case`.
+    SnakeCase,
+
+    /// Sibling parameters, following the same template text, with different names.
+    InconsistentName,
+
+    /// Mid-route wildcards not followed by `/`, which require the slower inline search.
+    InlineWildcard,
+
+    /// Templates with more parameters than can be stored without allocating.
+    TooManyParameters,
+}
+
+impl LintRule {
+    /// Every rule, in reporting order.
+    pub const ALL: &'static [Self] = &[
+        Self::TrailingSlash,
+        Self::SnakeCase,
+        Self::InconsistentName,
+        Self::InlineWildcard,
+        Self::TooManyParameters,
+    ];
+
+    /// The name of the rule, in `kebab-case`.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::TrailingSlash => "trailing-slash",
+            Self::SnakeCase => "snake-case",
+            Self::InconsistentName => "inconsistent-name",
+            Self::InlineWildcard => "inline-wildcard",
+            Self::TooManyParameters => "too-many-parameters",
+        }
+    }
+
+    const fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+impl fmt::Display for LintRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// The set of lint rules to run.
+///
+/// # Examples
+///
+/// ```rust
+/// use wayfind::{LintConfig, LintRule};
+///
+/// let config = LintConfig::new().without(LintRule::SnakeCase);
+/// assert!(config.is_enabled(LintRule::TrailingSlash));
+/// assert!(!config.is_enabled(LintRule::SnakeCase));
+/// ```
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub struct LintConfig {
+    rules: u8,
+}
+
+impl LintConfig {
+    /// Enables every rule.
+    #[must_use]
+    pub const fn new() -> Self {
+        let mut config = Self::none();
+        let mut index = 0;
+        while index < LintRule::ALL.len() {
+            config = config.with(LintRule::ALL[index]);
+            index += 1;
+        }
+
+        config
+    }
+
+    /// Enables no rules.
+    #[must_use]
+    pub const fn none() -> Self {
+        Self { rules: 0 }
+    }
+
+    /// Enables the given rule.
+    #[must_use]
+    pub const fn with(self, rule: LintRule) -> Self {
+        Self {
+            rules: self.rules | rule.bit(),
+        }
+    }
+
+    /// Disables the given rule.
+    #[must_use]
+    pub const fn without(self, rule: LintRule) -> Self {
+        Self {
+            rules: self.rules & !rule.bit(),
+        }
+    }
+
+    /// Whether the given rule is enabled.
+    #[must_use]
+    pub const fn is_enabled(self, rule: LintRule) -> bool {
+        self.rules & rule.bit() != 0
+    }
+}
+
+impl Default for LintConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An issue found by a lint rule.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Lint {
+    /// The rule that found the issue.
+    pub rule: LintRule,
+
+    /// The templates involved.
+    pub templates: Vec<String>,
+
+    /// A description of the issue.
+    pub message: String,
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}] {}", self.rule, self.message)
+    }
+}
+
+/// Runs every enabled rule over the given templates.
+pub(crate) fn lint(templates: &[&str], config: LintConfig) -> Vec<Lint> {
+    let parsed: Vec<(&str, Vec<Part<'_>>)> = templates
+        .iter()
+        .filter_map(|&template| {
+            let parsed = Template::new(template).ok()?;
+            Some((template, parsed.parts.into_iter().rev().collect()))
+        })
+        .collect();
+
+    let mut lints = vec![];
+    for &rule in LintRule::ALL {
+        if !config.is_enabled(rule) {
+            continue;
+        }
+
+        match rule {
+            LintRule::TrailingSlash => trailing_slash(&parsed, &mut lints),
+            LintRule::SnakeCase => snake_case(&parsed, &mut lints),
+            LintRule::InconsistentName => inconsistent_name(&parsed, &mut lints),
+            LintRule::InlineWildcard => inline_wildcard(&parsed, &mut lints),
+            LintRule::TooManyParameters => too_many_parameters(&parsed, &mut lints),
+        }
+    }
+
+    lints
+}
+
+/// Templates alongside their parts, in template order.
+type Parsed<'a> = [(&'a str, Vec<Part<'a>>)];
+
+fn parameters<'a>(parts: &'a [Part<'a>]) -> impl Iterator<Item = (usize, &'a str)> {
+    parts
+        .iter()
+        .enumerate()
+        .filter_map(|(index, part)| match part {
+            Part::Dynamic { name } | Part::Wildcard { name } => Some((index, *name)),
+            Part::Static { .. } => None,
+        })
+}
+
+fn trailing_slash(parsed: &Parsed<'_>, lints: &mut Vec<Lint>) {
+    for &(template, _) in parsed {
+        let Some(trimmed) = template
+            .strip_suffix('/')
+            .filter(|trimmed| !trimmed.is_empty())
+        else {
+            continue;
+        };
+
+        if parsed.iter().any(|&(other, _)| other == trimmed) {
+            lints.push(Lint {
+                rule: LintRule::TrailingSlash,
+                templates: vec![trimmed.into(), template.into()],
+                message: format!("`{trimmed}` and `{template}` differ only by a trailing slash"),
+            });
+        }
+    }
+}
+
+fn snake_case(parsed: &Parsed<'_>, lints: &mut Vec<Lint>) {
+    for (template, parts) in parsed {
+        for (_, name) in parameters(parts) {
+            let valid = name.starts_with(|char: char| char.is_ascii_lowercase() || char == '_')
+                && name
+                    .chars()
+                    .all(|char| char.is_ascii_lowercase() || char.is_ascii_digit() || char == '_');
+
+            if !valid {
+                lints.push(Lint {
+                    rule: LintRule::SnakeCase,
+                    templates: vec![(*template).into()],
+                    message: format!("parameter `{name}` in `{template}` is not snake_case"),
+                });
+            }
+        }
+    }
+}
+
+/// Flags parameters that share a parent in the builder tree, but not a name.
+///
+/// For example, `/users/<id>` and `/users/<user>/posts/<id>` name the parameter after `/users/` differently.
+fn inconsistent_name(parsed: &Parsed<'_>, lints: &mut Vec<Lint>) {
+    // Keyed by the template text before the parameter, and whether it's a wildcard.
+    let mut siblings: BTreeMap<(String, bool), Vec<(&str, &str)>> = BTreeMap::new();
+    for (template, parts) in parsed {
+        let mut text = String::new();
+        for part in parts {
+            let key = match part {
+                Part::Static { .. } => None,
+                Part::Dynamic { name } => Some((name, false)),
+                Part::Wildcard { name } => Some((name, true)),
+            };
+
+            if let Some((name, wildcard)) = key {
+                let names = siblings.entry((text.clone(), wildcard)).or_default();
+                if names.iter().all(|&(other, _)| other != *name) {
+                    names.push((name, template));
+                }
+            }
+
+            text.push_str(&part.to_string());
+        }
+    }
+
+    for ((text, _), names) in siblings {
+        if names.len() < 2 {
+            continue;
+        }
+
+        let usages: Vec<String> = names
+            .iter()
+            .map(|(name, template)| format!("`{name}` in `{template}`"))
+            .collect();
+
+        lints.push(Lint {
+            rule: LintRule::InconsistentName,
+            templates: names.iter().map(|&(_, template)| template.into()).collect(),
+            message: format!(
+                "sibling parameters after `{text}` have different names: {}",
+                usages.join(", ")
+            ),
+        });
+    }
+}
+
+fn inline_wildcard(parsed: &Parsed<'_>, lints: &mut Vec<Lint>) {
+    for (template, parts) in parsed {
+        for window in parts.windows(2) {
+            let [Part::Wildcard { name }, Part::Static { prefix }] = window else {
+                continue;
+            };
+
+            if prefix.first() != Some(&b'/') {
+                lints.push(Lint {
+                    rule: LintRule::InlineWildcard,
+                    templates: vec![(*template).into()],
+                    message: format!(
+                        "wildcard `<*{name}>` in `{template}` is not followed by `/`, requiring an inline search"
+                    ),
+                });
+            }
+        }
+    }
+}
+
+fn too_many_parameters(parsed: &Parsed<'_>, lints: &mut Vec<Lint>) {
+    for (template, parts) in parsed {
+        let count = parameters(parts).count();
+        if count > INLINE_PARAMETERS {
+            lints.push(Lint {
+                rule: LintRule::TooManyParameters,
+                templates: vec![(*template).into()],
+                message: format!(
+                    "`{template}` has {count} parameters, more than the {INLINE_PARAMETERS} stored without allocating"
+                ),
+            });
+        }
+    }
+}
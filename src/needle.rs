@@ -3,6 +3,7 @@ use core::num::NonZeroUsize;
 use crate::storage::Storage;
 
 /// Cached rightmost positions for `Contains` checks.
+#[derive(Debug)]
 pub(crate) struct NeedleCache {
     entries: Storage<(usize, Option<NonZeroUsize>), 8>,
 }
@@ -14,6 +15,11 @@ impl NeedleCache {
         }
     }
 
+    /// Forgets all cached positions, keeping any heap allocation for reuse.
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+    }
+
     /// The rightmost position of the needle, cached after first lookup.
     pub(crate) fn rightmost(&mut self, id: usize, needle: &[u8], path: &str) -> Option<usize> {
         if let Some((_, cached)) = self
@@ -2,29 +2,102 @@ use alloc::borrow::ToOwned as _;
 use alloc::boxed::Box;
 use alloc::format;
 use alloc::string::ToString as _;
+use alloc::vec::Vec;
 use core::fmt;
+use core::marker::PhantomData;
 use core::num::NonZeroUsize;
+use core::panic::Location;
 
 use crate::bounds::Bounds;
+use crate::coverage::Counter;
+#[cfg(feature = "explain")]
+use crate::explain::Step;
 use crate::needle::NeedleCache;
+use crate::parameters::{Capture, Captures};
+use crate::parser::Part;
 use crate::reachable::Reachable;
+use crate::router::SearchScratch;
 use crate::state::{DynamicState, EndWildcardState, StaticState, WildcardState};
 use crate::storage::Storage;
 use crate::suffixes::Suffixes;
+#[cfg(feature = "explain")]
+use crate::visitor::NodeKind;
+
+/// Records a search step, when explaining.
+///
+/// Expands to nothing without the `explain` feature, so regular searches are unaffected.
+macro_rules! explain {
+    ($ctx:expr, $step:expr) => {
+        #[cfg(feature = "explain")]
+        $ctx.record(|| $step);
+    };
+}
 
 /// Per-search state.
-pub(crate) struct SearchContext<'r, 'p> {
+pub(crate) struct SearchContext<'r, 'p, P = Captures<'r, 'p>> {
     pub needles: NeedleCache,
     pub caps: Storage<usize, 8>,
-    pub parameters: Storage<(&'r str, &'p str), 4>,
+    pub parameters: P,
+    /// The steps taken so far, when explaining.
+    #[cfg(feature = "explain")]
+    pub trace: Option<Vec<Step>>,
+    _marker: PhantomData<(&'r str, &'p str)>,
 }
 
 impl SearchContext<'_, '_> {
     pub(crate) const fn new() -> Self {
+        Self::with_sink(Captures::new())
+    }
+}
+
+impl<'r, 'p, P: Capture<'r, 'p>> SearchContext<'r, 'p, P> {
+    /// Captures parameters into the given sink.
+    pub(crate) const fn with_sink(parameters: P) -> Self {
         Self {
             needles: NeedleCache::new(),
             caps: Storage::new(),
-            parameters: Storage::new(),
+            parameters,
+            #[cfg(feature = "explain")]
+            trace: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Borrows the reusable buffers of a scratch space, capturing parameters into the given sink.
+    pub(crate) const fn from_scratch(scratch: &mut SearchScratch, parameters: P) -> Self {
+        Self {
+            needles: core::mem::replace(&mut scratch.needles, NeedleCache::new()),
+            caps: core::mem::replace(&mut scratch.caps, Storage::new()),
+            parameters,
+            #[cfg(feature = "explain")]
+            trace: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the reusable buffers to a scratch space, yielding the parameter sink.
+    pub(crate) fn into_scratch(self, scratch: &mut SearchScratch) -> P {
+        let Self {
+            mut needles,
+            mut caps,
+            parameters,
+            ..
+        } = self;
+
+        needles.clear();
+        caps.clear();
+
+        scratch.needles = needles;
+        scratch.caps = caps;
+
+        parameters
+    }
+
+    /// Records a step, if a trace was requested.
+    #[cfg(feature = "explain")]
+    fn record<F: FnOnce() -> Step>(&mut self, step: F) {
+        if let Some(trace) = &mut self.trace {
+            trace.push(step());
         }
     }
 
@@ -54,30 +127,57 @@ impl SearchContext<'_, '_> {
 
 /// Data stored at a leaf node.
 #[derive(Clone, Debug)]
-pub(crate) struct Data<T> {
-    pub data: T,
+pub(crate) struct Data {
+    /// The index of the stored values, shared by aliases.
+    pub slot: usize,
     pub template: Box<str>,
+    /// The aliased template, for aliases.
+    pub canonical: Option<Box<str>>,
+    pub location: &'static Location<'static>,
+    /// The number of searches matching this template.
+    pub hits: Counter,
 }
 
-/// Node children search approach.
+/// Values stored for a template.
 #[derive(Clone, Debug)]
-pub(crate) enum SearchMode {
+pub(crate) struct Value<T> {
+    pub data: T,
+    /// Further values for the same template, only allocated once a duplicate is inserted.
+    #[expect(
+        clippy::box_collection,
+        reason = "Keeps templates without duplicates to a single word"
+    )]
+    pub duplicates: Option<Box<Vec<T>>>,
+}
+
+/// How a node searches its parameter children.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub enum SearchMode {
     /// All children are whole segments.
     Segment,
     /// Children may have inline suffixes.
     Inline,
 }
 
+impl fmt::Display for SearchMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Segment => write!(f, "segment"),
+            Self::Inline => write!(f, "inline"),
+        }
+    }
+}
+
 /// An immutable node in the search tree.
 #[derive(Clone, Debug)]
-pub(crate) struct Node<S, T> {
+pub(crate) struct Node<S> {
     pub state: S,
-    pub data: Option<Data<T>>,
+    pub data: Option<Data>,
 
-    pub static_children: Box<[Node<StaticState, T>]>,
-    pub dynamic_children: Box<[Node<DynamicState, T>]>,
-    pub wildcard_children: Box<[Node<WildcardState, T>]>,
-    pub end_wildcard: Option<EndWildcardState<T>>,
+    pub static_children: Box<[Node<StaticState>]>,
+    pub dynamic_children: Box<[Node<DynamicState>]>,
+    pub wildcard_children: Box<[Node<WildcardState>]>,
+    pub end_wildcard: Option<EndWildcardState>,
 
     pub bounds: Bounds,
     pub reachable: Reachable,
@@ -87,33 +187,120 @@ pub(crate) struct Node<S, T> {
     pub wildcard_search: SearchMode,
 }
 
-impl<S, T> Node<S, T> {
+impl<S> Node<S> {
+    /// Creates an empty node, ready for incremental insertion.
+    pub(crate) fn new(state: S) -> Self {
+        Self {
+            state,
+            data: None,
+
+            static_children: Box::default(),
+            dynamic_children: Box::default(),
+            wildcard_children: Box::default(),
+            end_wildcard: None,
+
+            bounds: Bounds::default(),
+            reachable: Reachable::default(),
+            suffixes: Suffixes::default(),
+
+            dynamic_search: SearchMode::Segment,
+            wildcard_search: SearchMode::Segment,
+        }
+    }
+
     pub(crate) fn has_parameters(&self) -> bool {
         !self.dynamic_children.is_empty()
             || !self.wildcard_children.is_empty()
             || self.end_wildcard.is_some()
     }
 
-    pub(crate) fn search<'r, 'p>(
+    pub(crate) fn is_segment_only(&self) -> bool {
+        !self.has_parameters()
+            && self
+                .static_children
+                .iter()
+                .all(|child| child.state.prefix.first() == Some(&b'/'))
+    }
+
+    /// Finds existing data matching the same paths as the template.
+    ///
+    /// Mirrors `BuilderNode::conflict`, for compiled trees.
+    pub(crate) fn conflict(&self, parts: &[Part<'_>]) -> Option<&Data> {
+        let Some((part, remaining)) = parts.split_last() else {
+            return self.data.as_ref();
+        };
+
+        match part {
+            Part::Static { prefix } => self.conflict_static(remaining, prefix),
+            Part::Dynamic { .. } => self
+                .dynamic_children
+                .iter()
+                .find_map(|child| child.conflict(remaining)),
+            Part::Wildcard { .. } if remaining.is_empty() => {
+                self.end_wildcard.as_ref().map(|child| &child.data)
+            }
+            Part::Wildcard { .. } => self
+                .wildcard_children
+                .iter()
+                .find_map(|child| child.conflict(remaining)),
+        }
+    }
+
+    fn conflict_static(&self, parts: &[Part<'_>], prefix: &[u8]) -> Option<&Data> {
+        self.static_children
+            .iter()
+            .filter(|child| {
+                prefix.len() >= child.state.prefix.len()
+                    && child.state.prefix.iter().zip(prefix).all(|(a, b)| a == b)
+            })
+            .find_map(|child| {
+                let rest = &prefix[child.state.prefix.len()..];
+                if rest.is_empty() {
+                    child.conflict(parts)
+                } else {
+                    child.conflict_static(parts, rest)
+                }
+            })
+    }
+
+    pub(crate) fn search<'r, 'p, P: Capture<'r, 'p>>(
         &'r self,
-        ctx: &mut SearchContext<'r, 'p>,
+        ctx: &mut SearchContext<'r, 'p, P>,
         path: &'p str,
-    ) -> Option<&'r Data<T>> {
+    ) -> Option<&'r Data> {
         self.search_at(ctx, path, 0)
     }
 
-    fn search_at<'r, 'p>(
+    fn search_at<'r, 'p, P: Capture<'r, 'p>>(
         &'r self,
-        ctx: &mut SearchContext<'r, 'p>,
+        ctx: &mut SearchContext<'r, 'p, P>,
         path: &'p str,
         offset: usize,
-    ) -> Option<&'r Data<T>> {
+    ) -> Option<&'r Data> {
         if offset == path.len() {
+            explain!(
+                ctx,
+                self.data
+                    .as_ref()
+                    .map_or(Step::NoTemplate, |data| Step::Matched {
+                        template: data.template.to_string(),
+                    })
+            );
+
             return self.data.as_ref();
         }
 
         let length = path.len() - offset;
         if length < self.bounds.shortest() || length > self.bounds.longest() {
+            explain!(
+                ctx,
+                Step::OutOfBounds {
+                    remaining: length,
+                    shortest: self.bounds.shortest(),
+                    longest: self.bounds.longest(),
+                }
+            );
+
             return None;
         }
 
@@ -146,12 +333,12 @@ impl<S, T> Node<S, T> {
         self.search_end_wildcard(ctx, path, offset)
     }
 
-    fn search_static<'r, 'p>(
+    fn search_static<'r, 'p, P: Capture<'r, 'p>>(
         &'r self,
-        ctx: &mut SearchContext<'r, 'p>,
+        ctx: &mut SearchContext<'r, 'p, P>,
         path: &'p str,
         offset: usize,
-    ) -> Option<&'r Data<T>> {
+    ) -> Option<&'r Data> {
         let remaining = &path.as_bytes()[offset..];
 
         for child in &self.static_children {
@@ -164,55 +351,108 @@ impl<S, T> Node<S, T> {
                     .all(|(a, b)| a == b)
             {
                 let end = offset + child.state.prefix.len();
+                explain!(
+                    ctx,
+                    Step::Enter {
+                        prefix: child.state.to_string(),
+                        offset: end,
+                    }
+                );
+
                 if let Some(data) = child.search_at(ctx, path, end) {
                     return Some(data);
                 }
+
+                explain!(ctx, Step::Backtrack);
             }
         }
 
         None
     }
 
-    fn search_dynamic_segment<'r, 'p>(
+    fn search_dynamic_segment<'r, 'p, P: Capture<'r, 'p>>(
         &'r self,
-        ctx: &mut SearchContext<'r, 'p>,
+        ctx: &mut SearchContext<'r, 'p, P>,
         path: &'p str,
         offset: usize,
-    ) -> Option<&'r Data<T>> {
+    ) -> Option<&'r Data> {
         let remaining = &path.as_bytes()[offset..];
 
         for child in &self.dynamic_children {
             let id = child.state.id;
 
-            let window = (ctx.cap(id, offset, remaining.len()) + 1).min(remaining.len());
+            let cap = ctx.cap(id, offset, remaining.len());
+            if cap < remaining.len() {
+                explain!(
+                    ctx,
+                    Step::Memoized {
+                        parameter: child.state.to_string(),
+                        limit: cap,
+                    }
+                );
+            }
+
+            let window = (cap + 1).min(remaining.len());
             let limit = match memchr::memchr(b'/', &remaining[..window]) {
                 Some(limit) if limit > 0 => limit,
                 None if window == remaining.len() => remaining.len(),
                 Some(_) | None => {
+                    explain!(
+                        ctx,
+                        Step::Empty {
+                            parameter: child.state.to_string(),
+                        }
+                    );
+
                     ctx.lower(id, offset);
                     continue;
                 }
             };
 
             if remaining.len() - limit < child.bounds.shortest() {
+                explain!(
+                    ctx,
+                    Step::TooShort {
+                        parameter: child.state.to_string(),
+                    }
+                );
+
                 ctx.lower(id, offset);
                 continue;
             }
 
             if !child.reachable.check(&mut ctx.needles, path, offset) {
+                explain!(
+                    ctx,
+                    Step::Unreachable {
+                        parameter: child.state.to_string(),
+                    }
+                );
+
                 ctx.lower(id, offset);
                 continue;
             }
 
             let boundary = offset + limit;
+            explain!(
+                ctx,
+                Step::Capture {
+                    parameter: child.state.to_string(),
+                    value: path[offset..boundary].into(),
+                    suffix: false,
+                    kind: NodeKind::Dynamic,
+                }
+            );
+
             ctx.parameters
-                .push((&child.state.name, &path[offset..boundary]));
+                .capture(&child.state.name, path, offset..boundary);
 
             if let Some(result) = child.search_at(ctx, path, boundary) {
                 return Some(result);
             }
 
-            ctx.parameters.pop();
+            explain!(ctx, Step::Backtrack);
+            ctx.parameters.release();
             ctx.lower(id, offset);
         }
 
@@ -220,29 +460,52 @@ impl<S, T> Node<S, T> {
     }
 
     #[inline(never)]
-    fn search_dynamic_inline<'r, 'p>(
+    fn search_dynamic_inline<'r, 'p, P: Capture<'r, 'p>>(
         &'r self,
-        ctx: &mut SearchContext<'r, 'p>,
+        ctx: &mut SearchContext<'r, 'p, P>,
         path: &'p str,
         offset: usize,
-    ) -> Option<&'r Data<T>> {
+    ) -> Option<&'r Data> {
         let remaining = &path.as_bytes()[offset..];
 
         for child in &self.dynamic_children {
             let id = child.state.id;
 
             if remaining.len() <= child.bounds.shortest() {
+                explain!(
+                    ctx,
+                    Step::TooShort {
+                        parameter: child.state.to_string(),
+                    }
+                );
+
                 ctx.lower(id, offset);
                 continue;
             }
 
             if !child.reachable.check(&mut ctx.needles, path, offset) {
+                explain!(
+                    ctx,
+                    Step::Unreachable {
+                        parameter: child.state.to_string(),
+                    }
+                );
+
                 ctx.lower(id, offset);
                 continue;
             }
 
             let max = remaining.len() - child.bounds.shortest();
             let bound = ctx.cap(id, offset, max);
+            if bound < max {
+                explain!(
+                    ctx,
+                    Step::Memoized {
+                        parameter: child.state.to_string(),
+                        limit: bound,
+                    }
+                );
+            }
 
             let window = (bound + 1).min(remaining.len());
             let limit = memchr::memchr(b'/', &remaining[..window]);
@@ -251,15 +514,25 @@ impl<S, T> Node<S, T> {
             // Try boundaries with known suffix.
             for position in child.suffixes.positions(path, offset, cap) {
                 let boundary = offset + position;
+                explain!(
+                    ctx,
+                    Step::Capture {
+                        parameter: child.state.to_string(),
+                        value: path[offset..boundary].into(),
+                        suffix: true,
+                        kind: NodeKind::Dynamic,
+                    }
+                );
 
                 ctx.parameters
-                    .push((&child.state.name, &path[offset..boundary]));
+                    .capture(&child.state.name, path, offset..boundary);
 
                 if let Some(result) = child.search_at(ctx, path, boundary) {
                     return Some(result);
                 }
 
-                ctx.parameters.pop();
+                explain!(ctx, Step::Backtrack);
+                ctx.parameters.release();
             }
 
             // Try the segment end as a boundary.
@@ -267,25 +540,50 @@ impl<S, T> Node<S, T> {
                 Some(limit) if limit > 0 => limit,
                 None if window == remaining.len() => remaining.len(),
                 Some(_) | None => {
+                    explain!(
+                        ctx,
+                        Step::Empty {
+                            parameter: child.state.to_string(),
+                        }
+                    );
+
                     ctx.lower(id, offset);
                     continue;
                 }
             };
 
             if remaining.len() - limit < child.bounds.shortest() {
+                explain!(
+                    ctx,
+                    Step::TooShort {
+                        parameter: child.state.to_string(),
+                    }
+                );
+
                 ctx.lower(id, offset);
                 continue;
             }
 
             let boundary = offset + limit;
+            explain!(
+                ctx,
+                Step::Capture {
+                    parameter: child.state.to_string(),
+                    value: path[offset..boundary].into(),
+                    suffix: false,
+                    kind: NodeKind::Dynamic,
+                }
+            );
+
             ctx.parameters
-                .push((&child.state.name, &path[offset..boundary]));
+                .capture(&child.state.name, path, offset..boundary);
 
             if let Some(result) = child.search_at(ctx, path, boundary) {
                 return Some(result);
             }
 
-            ctx.parameters.pop();
+            explain!(ctx, Step::Backtrack);
+            ctx.parameters.release();
             ctx.lower(id, offset);
         }
 
@@ -293,29 +591,52 @@ impl<S, T> Node<S, T> {
     }
 
     #[inline(never)]
-    fn search_wildcard_segment<'r, 'p>(
+    fn search_wildcard_segment<'r, 'p, P: Capture<'r, 'p>>(
         &'r self,
-        ctx: &mut SearchContext<'r, 'p>,
+        ctx: &mut SearchContext<'r, 'p, P>,
         path: &'p str,
         offset: usize,
-    ) -> Option<&'r Data<T>> {
+    ) -> Option<&'r Data> {
         let remaining = &path.as_bytes()[offset..];
 
         for child in &self.wildcard_children {
             let id = child.state.id;
 
             if remaining.len() <= child.bounds.shortest() {
+                explain!(
+                    ctx,
+                    Step::TooShort {
+                        parameter: child.state.to_string(),
+                    }
+                );
+
                 ctx.lower(id, offset);
                 continue;
             }
 
             if !child.reachable.check(&mut ctx.needles, path, offset) {
+                explain!(
+                    ctx,
+                    Step::Unreachable {
+                        parameter: child.state.to_string(),
+                    }
+                );
+
                 ctx.lower(id, offset);
                 continue;
             }
 
             let max = remaining.len() - child.bounds.shortest();
             let cap = ctx.cap(id, offset, max);
+            if cap < max {
+                explain!(
+                    ctx,
+                    Step::Memoized {
+                        parameter: child.state.to_string(),
+                        limit: cap,
+                    }
+                );
+            }
             let upper = (cap + 1).min(remaining.len());
 
             let initial = memchr::memrchr(b'/', &remaining[..upper]);
@@ -326,19 +647,37 @@ impl<S, T> Node<S, T> {
             for position in positions.take_while(|&position| position > 0) {
                 let after = &remaining[position..];
                 if !child.suffixes.accepts(after) {
+                    explain!(
+                        ctx,
+                        Step::NoSuffix {
+                            parameter: child.state.to_string(),
+                            value: path[offset..offset + position].into(),
+                        }
+                    );
+
                     continue;
                 }
 
                 let boundary = offset + position;
+                explain!(
+                    ctx,
+                    Step::Capture {
+                        parameter: child.state.to_string(),
+                        value: path[offset..boundary].into(),
+                        suffix: true,
+                        kind: NodeKind::Wildcard,
+                    }
+                );
 
                 ctx.parameters
-                    .push((&child.state.name, &path[offset..boundary]));
+                    .capture(&child.state.name, path, offset..boundary);
 
                 if let Some(result) = child.search_at(ctx, path, boundary) {
                     return Some(result);
                 }
 
-                ctx.parameters.pop();
+                explain!(ctx, Step::Backtrack);
+                ctx.parameters.release();
             }
 
             ctx.lower(id, offset);
@@ -348,41 +687,74 @@ impl<S, T> Node<S, T> {
     }
 
     #[inline(never)]
-    fn search_wildcard_inline<'r, 'p>(
+    fn search_wildcard_inline<'r, 'p, P: Capture<'r, 'p>>(
         &'r self,
-        ctx: &mut SearchContext<'r, 'p>,
+        ctx: &mut SearchContext<'r, 'p, P>,
         path: &'p str,
         offset: usize,
-    ) -> Option<&'r Data<T>> {
+    ) -> Option<&'r Data> {
         let remaining = &path.as_bytes()[offset..];
 
         for child in &self.wildcard_children {
             let id = child.state.id;
 
             if remaining.len() <= child.bounds.shortest() {
+                explain!(
+                    ctx,
+                    Step::TooShort {
+                        parameter: child.state.to_string(),
+                    }
+                );
+
                 ctx.lower(id, offset);
                 continue;
             }
 
             if !child.reachable.check(&mut ctx.needles, path, offset) {
+                explain!(
+                    ctx,
+                    Step::Unreachable {
+                        parameter: child.state.to_string(),
+                    }
+                );
+
                 ctx.lower(id, offset);
                 continue;
             }
 
             let max = remaining.len() - child.bounds.shortest();
             let cap = ctx.cap(id, offset, max);
+            if cap < max {
+                explain!(
+                    ctx,
+                    Step::Memoized {
+                        parameter: child.state.to_string(),
+                        limit: cap,
+                    }
+                );
+            }
 
             for position in child.suffixes.positions(path, offset, cap) {
                 let boundary = offset + position;
+                explain!(
+                    ctx,
+                    Step::Capture {
+                        parameter: child.state.to_string(),
+                        value: path[offset..boundary].into(),
+                        suffix: true,
+                        kind: NodeKind::Wildcard,
+                    }
+                );
 
                 ctx.parameters
-                    .push((&child.state.name, &path[offset..boundary]));
+                    .capture(&child.state.name, path, offset..boundary);
 
                 if let Some(result) = child.search_at(ctx, path, boundary) {
                     return Some(result);
                 }
 
-                ctx.parameters.pop();
+                explain!(ctx, Step::Backtrack);
+                ctx.parameters.release();
             }
 
             ctx.lower(id, offset);
@@ -391,23 +763,41 @@ impl<S, T> Node<S, T> {
         None
     }
 
-    fn search_end_wildcard<'r, 'p>(
+    fn search_end_wildcard<'r, 'p, P: Capture<'r, 'p>>(
         &'r self,
-        ctx: &mut SearchContext<'r, 'p>,
+        ctx: &mut SearchContext<'r, 'p, P>,
         path: &'p str,
         offset: usize,
-    ) -> Option<&'r Data<T>> {
+    ) -> Option<&'r Data> {
         let child = self.end_wildcard.as_ref()?;
-        ctx.parameters.push((&child.name, &path[offset..]));
+        explain!(
+            ctx,
+            Step::Capture {
+                parameter: child.to_string(),
+                value: path[offset..].into(),
+                suffix: false,
+                kind: NodeKind::EndWildcard,
+            }
+        );
+
+        explain!(
+            ctx,
+            Step::Matched {
+                template: child.data.template.to_string(),
+            }
+        );
+
+        ctx.parameters
+            .capture(&child.name, path, offset..path.len());
         Some(&child.data)
     }
 }
 
-impl<S: fmt::Display, T> fmt::Display for Node<S, T> {
+impl<S: fmt::Display> fmt::Display for Node<S> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fn display_node<S: fmt::Display, T>(
+        fn display_node<S: fmt::Display>(
             f: &mut fmt::Formatter<'_>,
-            node: &Node<S, T>,
+            node: &Node<S>,
             padding: &str,
             is_root: bool,
             is_last: bool,
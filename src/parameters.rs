@@ -0,0 +1,243 @@
+use alloc::vec::Vec;
+use core::iter::Copied;
+use core::ops::{Index, Range};
+use core::slice::Iter;
+
+use crate::storage::Storage;
+
+/// A destination for parameters captured during a search.
+///
+/// Parameters are pushed as they're tentatively matched, in template order.
+/// When the search backtracks, the most recent parameter is popped.
+///
+/// Implemented for `()`, which discards all parameters, and for `Vec<(&str, &str)>`.
+///
+/// # Examples
+///
+/// ```rust
+/// use wayfind::{ParameterSink, RouterBuilder};
+///
+/// #[derive(Default)]
+/// struct Ids<'p> {
+///     values: Vec<&'p str>,
+/// }
+///
+/// impl<'r, 'p> ParameterSink<'r, 'p> for Ids<'p> {
+///     fn push(&mut self, _: &'r str, value: &'p str) {
+///         self.values.push(value);
+///     }
+///
+///     fn pop(&mut self) {
+///         self.values.pop();
+///     }
+/// }
+///
+/// let mut builder = RouterBuilder::new();
+/// builder.insert("/users/<user>/posts/<post>", 1)?;
+///
+/// let router = builder.build();
+///
+/// let mut ids = Ids::default();
+/// let search = router.search_into(&mut ids, "/users/123/posts/456").unwrap();
+/// assert_eq!(search.data(), &1);
+/// assert_eq!(ids.values, ["123", "456"]);
+/// # Ok::<_, Box<dyn core::error::Error>>(())
+/// ```
+pub trait ParameterSink<'r, 'p> {
+    /// Captures a parameter.
+    fn push(&mut self, name: &'r str, value: &'p str);
+
+    /// Discards the most recently captured parameter.
+    fn pop(&mut self);
+}
+
+impl<'r, 'p> ParameterSink<'r, 'p> for () {
+    #[inline]
+    fn push(&mut self, _: &'r str, _: &'p str) {}
+
+    #[inline]
+    fn pop(&mut self) {}
+}
+
+impl<'r, 'p> ParameterSink<'r, 'p> for Vec<(&'r str, &'p str)> {
+    #[inline]
+    fn push(&mut self, name: &'r str, value: &'p str) {
+        self.push((name, value));
+    }
+
+    #[inline]
+    fn pop(&mut self) {
+        self.pop();
+    }
+}
+
+impl<'r, 'p, S: ParameterSink<'r, 'p> + ?Sized> ParameterSink<'r, 'p> for &mut S {
+    #[inline]
+    fn push(&mut self, name: &'r str, value: &'p str) {
+        (**self).push(name, value);
+    }
+
+    #[inline]
+    fn pop(&mut self) {
+        (**self).pop();
+    }
+}
+
+/// Receives parameters from the search, alongside where they start in the path.
+///
+/// Implemented for every [`ParameterSink`], which only receives the values.
+pub(crate) trait Capture<'r, 'p> {
+    /// Captures the parameter spanning the given range of the path.
+    fn capture(&mut self, name: &'r str, path: &'p str, span: Range<usize>);
+
+    /// Discards the most recently captured parameter.
+    fn release(&mut self);
+}
+
+impl<'r, 'p, S: ParameterSink<'r, 'p>> Capture<'r, 'p> for S {
+    #[inline]
+    fn capture(&mut self, name: &'r str, path: &'p str, span: Range<usize>) {
+        self.push(name, &path[span]);
+    }
+
+    #[inline]
+    fn release(&mut self) {
+        self.pop();
+    }
+}
+
+/// The number of parameters the default sink stores without allocating.
+pub(crate) const INLINE_PARAMETERS: usize = 4;
+
+/// The default sink, storing up to 4 parameters inline, alongside their start offsets.
+#[derive(Debug)]
+pub(crate) struct Captures<'r, 'p> {
+    parameters: Storage<(&'r str, &'p str), INLINE_PARAMETERS>,
+    starts: Storage<usize, INLINE_PARAMETERS>,
+}
+
+impl<'r, 'p> Captures<'r, 'p> {
+    pub(crate) const fn new() -> Self {
+        Self {
+            parameters: Storage::new(),
+            starts: Storage::new(),
+        }
+    }
+
+    pub(crate) fn as_slice(&self) -> &[(&'r str, &'p str)] {
+        self.parameters.as_slice()
+    }
+
+    /// The byte range of each parameter within the searched path.
+    pub(crate) fn spans(&self) -> impl Iterator<Item = (&'r str, Range<usize>)> + '_ {
+        self.parameters
+            .as_slice()
+            .iter()
+            .zip(self.starts.as_slice())
+            .map(|(&(name, value), &start)| (name, start..start + value.len()))
+    }
+}
+
+impl<'r, 'p> Capture<'r, 'p> for Captures<'r, 'p> {
+    #[inline]
+    fn capture(&mut self, name: &'r str, path: &'p str, span: Range<usize>) {
+        self.starts.push(span.start);
+        self.parameters.push((name, &path[span]));
+    }
+
+    #[inline]
+    fn release(&mut self) {
+        self.starts.pop();
+        self.parameters.pop();
+    }
+}
+
+/// A read-only view over matched parameters, with lookup by name.
+///
+/// Parameters are kept in template order.
+/// Lookups scan the parameters linearly, returning the first match.
+#[derive(Clone, Copy, Debug)]
+pub struct Parameters<'a, 'r, 'p> {
+    parameters: &'a [(&'r str, &'p str)],
+}
+
+impl<'a, 'r, 'p> Parameters<'a, 'r, 'p> {
+    pub(crate) const fn new(parameters: &'a [(&'r str, &'p str)]) -> Self {
+        Self { parameters }
+    }
+
+    /// The value of the parameter with the given name.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&'p str> {
+        self.parameters
+            .iter()
+            .find(|(key, _)| *key == name)
+            .map(|&(_, value)| value)
+    }
+
+    /// The parameter at the given position, in template order.
+    #[must_use]
+    pub fn get_index(&self, index: usize) -> Option<(&'r str, &'p str)> {
+        self.parameters.get(index).copied()
+    }
+
+    /// Whether a parameter with the given name was matched.
+    #[must_use]
+    pub fn contains(&self, name: &str) -> bool {
+        self.get(name).is_some()
+    }
+
+    /// The number of matched parameters.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.parameters.len()
+    }
+
+    /// Whether no parameters were matched.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.parameters.is_empty()
+    }
+
+    /// Iterates over the parameters as key-value pairs, in template order.
+    pub fn iter(&self) -> Copied<Iter<'a, (&'r str, &'p str)>> {
+        self.parameters.iter().copied()
+    }
+
+    /// The parameters as a slice of key-value pairs.
+    #[must_use]
+    pub const fn as_slice(&self) -> &'a [(&'r str, &'p str)] {
+        self.parameters
+    }
+}
+
+impl<'a, 'r, 'p> IntoIterator for Parameters<'a, 'r, 'p> {
+    type Item = (&'r str, &'p str);
+    type IntoIter = Copied<Iter<'a, (&'r str, &'p str)>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, 'r, 'p> IntoIterator for &Parameters<'a, 'r, 'p> {
+    type Item = (&'r str, &'p str);
+    type IntoIter = Copied<Iter<'a, (&'r str, &'p str)>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl Index<&str> for Parameters<'_, '_, '_> {
+    type Output = str;
+
+    /// # Panics
+    ///
+    /// When no parameter with the given name was matched.
+    #[expect(clippy::panic, reason = "Missing keys panic, like other maps")]
+    fn index(&self, index: &str) -> &Self::Output {
+        self.get(index)
+            .unwrap_or_else(|| panic!("no parameter named `{index}`"))
+    }
+}
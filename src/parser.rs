@@ -1,6 +1,7 @@
 use alloc::string::{String, ToString as _};
 use alloc::vec;
 use alloc::vec::Vec;
+use core::fmt;
 
 use crate::errors::InsertError;
 
@@ -15,6 +16,16 @@ pub(crate) enum Part<'a> {
     Wildcard { name: &'a str },
 }
 
+impl fmt::Display for Part<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Static { prefix } => write!(f, "{}", String::from_utf8_lossy(prefix)),
+            Self::Dynamic { name } => write!(f, "<{name}>"),
+            Self::Wildcard { name } => write!(f, "<*{name}>"),
+        }
+    }
+}
+
 /// A parsed template.
 #[derive(Clone, Eq, PartialEq, Debug)]
 pub(crate) struct Template<'a> {
@@ -31,7 +42,11 @@ impl<'a> Template<'a> {
         }
 
         if input[0] != b'/' {
-            return Err(InsertError::MissingSlash);
+            let first = template.chars().next().map_or(1, char::len_utf8);
+            return Err(InsertError::MissingSlash {
+                template: template.into(),
+                span: 0..first,
+            });
         }
 
         let mut parts = vec![];
@@ -42,14 +57,17 @@ impl<'a> Template<'a> {
         while cursor < input.len() {
             match input[cursor] {
                 b'<' => {
-                    let (part, next) = Self::parse_parameter_part(input, cursor)?;
+                    let (part, next) = Self::parse_parameter_part(template, cursor)?;
 
                     // Check for touching parameters.
                     if seen_parameters
                         .last()
                         .is_some_and(|&(_, last)| cursor == last)
                     {
-                        return Err(InsertError::TouchingParameters);
+                        return Err(InsertError::TouchingParameters {
+                            template: template.into(),
+                            span: cursor..next,
+                        });
                     }
 
                     // Check for duplicate names.
@@ -57,6 +75,8 @@ impl<'a> Template<'a> {
                         if seen_parameters.iter().any(|(existing, _)| existing == name) {
                             return Err(InsertError::DuplicateParameter {
                                 name: String::from(*name),
+                                template: template.into(),
+                                span: cursor..next,
                             });
                         }
 
@@ -67,7 +87,10 @@ impl<'a> Template<'a> {
                     cursor = next;
                 }
                 b'>' => {
-                    return Err(InsertError::UnbalancedAngle);
+                    return Err(InsertError::UnbalancedAngle {
+                        template: template.into(),
+                        span: cursor..cursor + 1,
+                    });
                 }
                 _ => {
                     let (part, next_cursor) = Self::parse_static_part(input, cursor);
@@ -82,6 +105,57 @@ impl<'a> Template<'a> {
         Ok(Self { parts })
     }
 
+    /// Parses a template about to be inserted, tracing why it was rejected.
+    pub(crate) fn insertable(template: &'a str) -> Result<Self, InsertError> {
+        let parsed = Self::new(template);
+
+        #[cfg(feature = "tracing")]
+        if let Err(error) = &parsed {
+            tracing::debug!(template, %error, "rejected template");
+        }
+
+        parsed
+    }
+
+    /// An example path matched by this template, and any structurally equivalent template.
+    pub(crate) fn witness(&self) -> String {
+        let mut path = Vec::new();
+        for part in self.parts.iter().rev() {
+            match part {
+                Part::Static { prefix } => path.extend_from_slice(prefix),
+                Part::Dynamic { .. } => path.push(b'x'),
+                Part::Wildcard { .. } => path.extend_from_slice(b"x/y"),
+            }
+        }
+
+        String::from_utf8_lossy(&path).into_owned()
+    }
+
+    /// Each part of this template, in path order.
+    #[cfg(feature = "tracing")]
+    pub(crate) fn breakdown(&self) -> Vec<String> {
+        self.parts
+            .iter()
+            .rev()
+            .map(alloc::string::ToString::to_string)
+            .collect()
+    }
+
+    /// The parameter names of this template, sorted.
+    pub(crate) fn parameters(&self) -> Vec<&'a str> {
+        let mut names: Vec<&str> = self
+            .parts
+            .iter()
+            .filter_map(|part| match part {
+                Part::Dynamic { name } | Part::Wildcard { name } => Some(*name),
+                Part::Static { .. } => None,
+            })
+            .collect();
+
+        names.sort_unstable();
+        names
+    }
+
     fn parse_static_part(input: &'a [u8], cursor: usize) -> (Part<'a>, usize) {
         let end = memchr::memchr2(b'<', b'>', &input[cursor..])
             .map_or(input.len(), |position| cursor + position);
@@ -91,35 +165,52 @@ impl<'a> Template<'a> {
     }
 
     fn parse_parameter_part(
-        input: &'a [u8],
+        template: &'a str,
         cursor: usize,
     ) -> Result<(Part<'a>, usize), InsertError> {
+        let input = template.as_bytes();
+
         let start = cursor + 1;
         let end = memchr::memchr(b'>', &input[start..])
             .map(|position| start + position)
-            .ok_or(InsertError::UnbalancedAngle)?;
+            .ok_or_else(|| InsertError::UnbalancedAngle {
+                template: template.into(),
+                span: cursor..start,
+            })?;
+
+        let span = cursor..end + 1;
 
         let content = &input[start..end];
         if content.is_empty() {
-            return Err(InsertError::EmptyParameter);
+            return Err(InsertError::EmptyParameter {
+                template: template.into(),
+                span,
+            });
         }
 
         let is_wildcard = content.starts_with(b"*");
         let name = if is_wildcard { &content[1..] } else { content };
 
         if is_wildcard && name.is_empty() {
-            return Err(InsertError::EmptyParameter);
+            return Err(InsertError::EmptyParameter {
+                template: template.into(),
+                span,
+            });
         }
 
         if name.iter().any(|&c| INVALID_PARAM_CHARS.contains(&c)) {
             return Err(InsertError::InvalidParameter {
                 name: String::from_utf8_lossy(name).to_string(),
+                template: template.into(),
+                span,
             });
         }
 
         let name: &'a str =
             core::str::from_utf8(name).map_err(|_err| InsertError::InvalidParameter {
                 name: String::from_utf8_lossy(name).to_string(),
+                template: template.into(),
+                span: span.clone(),
             })?;
 
         let part = if is_wildcard {
@@ -134,6 +225,8 @@ impl<'a> Template<'a> {
 
 #[cfg(test)]
 mod tests {
+    use alloc::format;
+
     use similar_asserts::assert_eq;
 
     use super::*;
@@ -187,6 +280,12 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parser_witness() {
+        let template = Template::new("/users/<id>/files/<*path>.<ext>").unwrap();
+        assert_eq!(template.witness(), "/users/x/files/x/y.x");
+    }
+
     #[test]
     fn parser_error_empty() {
         let error = Template::new("").unwrap_err();
@@ -229,6 +328,58 @@ mod tests {
         insta::assert_snapshot!(error, @"duplicate parameter name `id`");
     }
 
+    #[test]
+    fn parser_error_spans() {
+        let error = Template::new("abc").unwrap_err();
+        insta::assert_snapshot!(format!("{error:#}"), @r"
+        missing leading slash
+        abc
+        ^
+        ");
+
+        let error = Template::new("/users/<id/profile").unwrap_err();
+        insta::assert_snapshot!(format!("{error:#}"), @r"
+        unbalanced angle bracket
+        /users/<id/profile
+               ^
+        ");
+
+        let error = Template::new("/users/id>/profile").unwrap_err();
+        insta::assert_snapshot!(format!("{error:#}"), @r"
+        unbalanced angle bracket
+        /users/id>/profile
+                 ^
+        ");
+
+        let error = Template::new("/users/<>/profile").unwrap_err();
+        insta::assert_snapshot!(format!("{error:#}"), @r"
+        empty parameter name
+        /users/<>/profile
+               ^^
+        ");
+
+        let error = Template::new("/ünïcode/<user*name>").unwrap_err();
+        insta::assert_snapshot!(format!("{error:#}"), @r"
+        invalid parameter name `user*name`
+        /ünïcode/<user*name>
+                 ^^^^^^^^^^^
+        ");
+
+        let error = Template::new("/users/<id>/posts/<id>").unwrap_err();
+        insta::assert_snapshot!(format!("{error:#}"), @r"
+        duplicate parameter name `id`
+        /users/<id>/posts/<id>
+                          ^^^^
+        ");
+
+        let error = Template::new("/users/<id><*name>/edit").unwrap_err();
+        insta::assert_snapshot!(format!("{error:#}"), @r"
+        parameters must be separated by a static character
+        /users/<id><*name>/edit
+                   ^^^^^^^
+        ");
+    }
+
     #[test]
     fn parser_error_empty_wildcard() {
         let error = Template::new("/files/<*>").unwrap_err();
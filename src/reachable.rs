@@ -70,11 +70,34 @@ impl Reachable {
                 .any(|group| group.check(needles, path, offset))
     }
 
+    /// The number of condition groups.
+    pub(crate) fn groups(&self) -> usize {
+        self.groups.len()
+    }
+
+    /// The estimated heap size, in bytes.
+    pub(crate) fn heap_size(&self) -> usize {
+        self.groups
+            .iter()
+            .map(|group| {
+                let conditions: usize = group
+                    .conditions
+                    .iter()
+                    .map(|condition| match condition {
+                        Condition::EndsWith(bytes) | Condition::Contains { needle: bytes, .. } => {
+                            bytes.len()
+                        }
+                    })
+                    .sum();
+
+                size_of_val(&*group.conditions) + conditions
+            })
+            .sum::<usize>()
+            + size_of_val(&*self.groups)
+    }
+
     /// Computes reachability conditions for a node's subtree.
-    pub(crate) fn compute<S, T>(
-        node: &Node<S, T>,
-        needles: &mut BTreeMap<Box<[u8]>, usize>,
-    ) -> Self {
+    pub(crate) fn compute<S>(node: &Node<S>, needles: &mut BTreeMap<Box<[u8]>, usize>) -> Self {
         // Nodes with data or end wildcards are always reachable.
         if node.data.is_some() || node.end_wildcard.is_some() {
             return Self::default();
@@ -102,8 +125,8 @@ impl Reachable {
     }
 
     /// Walks a static subtree, returning the constraint groups it produces.
-    fn walk_static<T>(
-        node: &Node<StaticState, T>,
+    fn walk_static(
+        node: &Node<StaticState>,
         prefix: &mut Vec<u8>,
         needles: &mut BTreeMap<Box<[u8]>, usize>,
     ) -> Vec<Group> {
@@ -173,7 +196,7 @@ impl Reachable {
     }
 
     /// Yields the reachable constraint groups of parameter children.
-    fn parameter_groups<S, T>(node: &Node<S, T>) -> impl Iterator<Item = &[Group]> {
+    fn parameter_groups<S>(node: &Node<S>) -> impl Iterator<Item = &[Group]> {
         node.dynamic_children
             .iter()
             .map(|child| &*child.reachable.groups)
@@ -1,46 +1,452 @@
+use alloc::string::{String, ToString as _};
+use alloc::vec::Vec;
 use core::fmt;
+use core::iter::{self, Chain, Once};
+use core::ops::Range;
+use core::panic::Location;
+use core::slice::Iter;
 
-use crate::node::{Node, SearchContext};
+use crate::compiler::Compiler;
+use crate::coverage::Counter;
+#[cfg(feature = "coverage")]
+use crate::coverage::Coverage;
+#[cfg(feature = "serde")]
+use crate::deserializer::ParametersDeserializer;
+#[cfg(feature = "serde")]
+use crate::errors::DeserializeError;
+use crate::errors::InsertError;
+#[cfg(feature = "explain")]
+use crate::explain::Explanation;
+use crate::export::{Format, Graph};
+use crate::needle::NeedleCache;
+use crate::node::{Data, Node, SearchContext, Value};
+use crate::parameters::{Captures, ParameterSink, Parameters};
+use crate::parser::Template;
 use crate::state::RootState;
+use crate::stats::RouterStats;
 use crate::storage::Storage;
+use crate::visitor::{NodeRef, Visitor};
 
-/// Stores data from a successful router match.
+/// The template matched by a search, without any captured parameters.
+///
+/// Returned by [`Router::search_into`], which captures parameters into a separate sink.
 #[derive(Debug)]
-pub struct Match<'r, 'p, T> {
+pub struct Route<'r, T> {
     data: &'r T,
+    duplicates: &'r [T],
     template: &'r str,
-    parameters: Storage<(&'r str, &'p str), 4>,
+    canonical: &'r str,
 }
 
-impl<'r, 'p, T> Match<'r, 'p, T> {
+impl<'r, T> Route<'r, T> {
+    fn new(data: &'r Data, value: &'r Value<T>) -> Self {
+        Self {
+            data: &value.data,
+            duplicates: value.duplicates.as_deref().map_or(&[], Vec::as_slice),
+            template: &data.template,
+            canonical: data.canonical.as_deref().unwrap_or(&data.template),
+        }
+    }
+
     /// A reference to the data associated with the matched template.
     #[must_use]
     pub const fn data(&self) -> &'r T {
         self.data
     }
 
+    /// Every value associated with the matched template, in insertion order.
+    ///
+    /// See [`Match::values`].
+    pub fn values(&self) -> Chain<Once<&'r T>, Iter<'r, T>> {
+        iter::once(self.data).chain(self.duplicates)
+    }
+
     /// The matched template string.
     #[must_use]
     pub const fn template(&self) -> &'r str {
         self.template
     }
 
+    /// The template the matched template is an alias of.
+    ///
+    /// See [`Match::canonical_template`].
+    #[must_use]
+    pub const fn canonical_template(&self) -> &'r str {
+        self.canonical
+    }
+}
+
+/// Stores data from a successful router match.
+#[derive(Debug)]
+pub struct Match<'r, 'p, T> {
+    route: Route<'r, T>,
+    path: &'p str,
+    parameters: Captures<'r, 'p>,
+}
+
+impl<'r, 'p, T> Match<'r, 'p, T> {
+    pub(crate) fn new(
+        data: &'r Data,
+        value: &'r Value<T>,
+        path: &'p str,
+        parameters: Captures<'r, 'p>,
+    ) -> Self {
+        Self {
+            route: Route::new(data, value),
+            path,
+            parameters,
+        }
+    }
+
+    /// A reference to the data associated with the matched template.
+    #[must_use]
+    pub const fn data(&self) -> &'r T {
+        self.route.data()
+    }
+
+    /// Every value associated with the matched template, in insertion order.
+    ///
+    /// Only routers built with [`RouterBuilder::with_duplicates`](crate::RouterBuilder::with_duplicates) store more than one value per template.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wayfind::RouterBuilder;
+    ///
+    /// let mut builder = RouterBuilder::new().with_duplicates();
+    /// builder.insert("/events/<name>", "audit")?;
+    /// builder.insert("/events/<name>", "metrics")?;
+    ///
+    /// let router = builder.build();
+    /// let search = router.search("/events/login").unwrap();
+    /// assert_eq!(search.values().collect::<Vec<_>>(), [&"audit", &"metrics"]);
+    /// # Ok::<_, Box<dyn core::error::Error>>(())
+    /// ```
+    pub fn values(&self) -> Chain<Once<&'r T>, Iter<'r, T>> {
+        self.route.values()
+    }
+
+    /// The matched template string.
+    #[must_use]
+    pub const fn template(&self) -> &'r str {
+        self.route.template()
+    }
+
+    /// The template the matched template is an alias of.
+    ///
+    /// Identical to [`Match::template`], unless the matched template was inserted with [`RouterBuilder::alias`](crate::RouterBuilder::alias).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wayfind::RouterBuilder;
+    ///
+    /// let mut builder = RouterBuilder::new();
+    /// builder.insert("/users/<id>", 1)?;
+    /// builder.alias("/u/<id>", "/users/<id>")?;
+    ///
+    /// let router = builder.build();
+    /// let search = router.search("/u/123").unwrap();
+    /// assert_eq!(search.data(), &1);
+    /// assert_eq!(search.template(), "/u/<id>");
+    /// assert_eq!(search.canonical_template(), "/users/<id>");
+    /// # Ok::<_, Box<dyn core::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub const fn canonical_template(&self) -> &'r str {
+        self.route.canonical_template()
+    }
+
+    /// The matched template, without the captured parameters.
+    #[must_use]
+    pub const fn route(&self) -> &Route<'r, T> {
+        &self.route
+    }
+
     /// The matched parameters as key-value pairs.
     #[must_use]
     pub fn parameters(&self) -> &[(&'r str, &'p str)] {
         self.parameters.as_slice()
     }
+
+    /// The searched path.
+    #[must_use]
+    pub const fn path(&self) -> &'p str {
+        self.path
+    }
+
+    /// The byte ranges of each matched parameter within the searched path.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wayfind::RouterBuilder;
+    ///
+    /// let mut builder = RouterBuilder::new();
+    /// builder.insert("/users/<id>/files/<name>.pdf", 1)?;
+    ///
+    /// let router = builder.build();
+    ///
+    /// let search = router.search("/users/123/files/report.pdf").unwrap();
+    /// assert_eq!(
+    ///     search.parameter_spans().collect::<Vec<_>>(),
+    ///     [("id", 7..10), ("name", 17..23)],
+    /// );
+    /// assert_eq!(
+    ///     search.static_spans().collect::<Vec<_>>(),
+    ///     [0..7, 10..17, 23..27],
+    /// );
+    /// # Ok::<_, Box<dyn core::error::Error>>(())
+    /// ```
+    pub fn parameter_spans(&self) -> impl Iterator<Item = (&'r str, Range<usize>)> + '_ {
+        self.parameters.spans()
+    }
+
+    /// The byte ranges of the static pieces surrounding the matched parameters.
+    pub fn static_spans(&self) -> impl Iterator<Item = Range<usize>> + '_ {
+        let starts = iter::once(0).chain(self.parameter_spans().map(|(_, span)| span.end));
+        let ends = self
+            .parameter_spans()
+            .map(|(_, span)| span.start)
+            .chain(iter::once(self.path.len()));
+
+        starts
+            .zip(ends)
+            .filter(|(start, end)| start < end)
+            .map(|(start, end)| start..end)
+    }
+
+    /// A keyed view over the matched parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wayfind::RouterBuilder;
+    ///
+    /// let mut builder = RouterBuilder::new();
+    /// builder.insert("/users/<id>/files/<*path>", 1)?;
+    ///
+    /// let router = builder.build();
+    ///
+    /// let search = router.search("/users/123/files/docs/report.pdf").unwrap();
+    /// let params = search.params();
+    /// assert_eq!(params.len(), 2);
+    /// assert!(params.contains("path"));
+    /// assert_eq!(&params["id"], "123");
+    /// # Ok::<_, Box<dyn core::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn params(&self) -> Parameters<'_, 'r, 'p> {
+        Parameters::new(self.parameters.as_slice())
+    }
+
+    /// The value of the parameter with the given name.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&'p str> {
+        self.params().get(name)
+    }
+
+    /// The parameter at the given position, in template order.
+    #[must_use]
+    pub fn get_index(&self, index: usize) -> Option<(&'r str, &'p str)> {
+        self.params().get_index(index)
+    }
+
+    /// Deserializes the matched parameters into a typed value.
+    ///
+    /// Structs and maps are keyed by parameter name, while tuples and sequences are positional.
+    /// Other types, such as integers or strings, require exactly one parameter.
+    ///
+    /// Wildcards can be deserialized as either a `String`, or a `Vec` of their `/` delimited segments.
+    /// A lone parameter deserialized as a sequence also yields its segments.
+    ///
+    /// # Errors
+    ///
+    /// When a parameter is missing, or its value can't be parsed into the expected type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde::Deserialize;
+    /// use wayfind::RouterBuilder;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Params<'p> {
+    ///     id: u32,
+    ///     #[serde(borrow)]
+    ///     path: Vec<&'p str>,
+    /// }
+    ///
+    /// let mut builder = RouterBuilder::new();
+    /// builder.insert("/users/<id>/files/<*path>", 1)?;
+    ///
+    /// let router = builder.build();
+    ///
+    /// let search = router.search("/users/123/files/docs/report.pdf").unwrap();
+    /// let params: Params<'_> = search.deserialize()?;
+    /// assert_eq!(params.id, 123);
+    /// assert_eq!(params.path, ["docs", "report.pdf"]);
+    /// # Ok::<_, Box<dyn core::error::Error>>(())
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn deserialize<D: serde::Deserialize<'p>>(&self) -> Result<D, DeserializeError> {
+        D::deserialize(ParametersDeserializer::new(self.parameters()))
+    }
+}
+
+/// Reusable search state, for use with [`Router::search_with`].
+///
+/// Keep one per thread or connection.
+/// The needle and memoization buffers are kept between searches, so once grown, they no longer allocate.
+///
+/// [`Router::search_with`] still stores parameters in the returned [`Match`], which allocates for templates with more than 4 parameters.
+/// To reuse a parameter buffer as well, pass one alongside the scratch space to [`Router::search_into_with`].
+#[derive(Debug)]
+pub struct SearchScratch {
+    pub(crate) needles: NeedleCache,
+    pub(crate) caps: Storage<usize, 8>,
+}
+
+impl SearchScratch {
+    /// Creates a new, empty scratch space.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            needles: NeedleCache::new(),
+            caps: Storage::new(),
+        }
+    }
 }
 
 /// An immutable, optimized router.
+///
+/// Keeps the needle table built during compilation, so [`Router::insert`] can reuse it.
+/// The table is counted in [`RouterStats::heap_size`], and cloned along with the router.
 #[derive(Clone)]
 pub struct Router<T> {
-    root: Node<RootState, T>,
+    root: Node<RootState>,
+    values: Vec<Value<T>>,
+    compiler: Compiler,
+    duplicates: bool,
+    misses: Counter,
 }
 
 impl<T> Router<T> {
-    pub(crate) const fn new(root: Node<RootState, T>) -> Self {
-        Self { root }
+    pub(crate) const fn new(
+        root: Node<RootState>,
+        values: Vec<Value<T>>,
+        compiler: Compiler,
+        duplicates: bool,
+    ) -> Self {
+        Self {
+            root,
+            values,
+            compiler,
+            duplicates,
+            misses: Counter::new(),
+        }
+    }
+
+    /// Inserts a template with associated data into the compiled router.
+    ///
+    /// Only the nodes along the template's path are recompiled, so this is much cheaper than rebuilding the router.
+    /// The result searches identically to a router built with the same templates.
+    ///
+    /// Re-inserting an identical template is allowed when the router was built with [`RouterBuilder::with_duplicates`](crate::RouterBuilder::with_duplicates).
+    ///
+    /// # Errors
+    ///
+    /// When the template is malformed or conflicts with an existing route.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wayfind::RouterBuilder;
+    ///
+    /// let mut builder = RouterBuilder::new();
+    /// builder.insert("/users/<id>", 1)?;
+    ///
+    /// let mut router = builder.build();
+    /// router.insert("/users/<id>/posts", 2)?;
+    ///
+    /// let search = router.search("/users/123/posts").unwrap();
+    /// assert_eq!(search.data(), &2);
+    /// # Ok::<_, Box<dyn core::error::Error>>(())
+    /// ```
+    #[track_caller]
+    pub fn insert(&mut self, template: &str, data: T) -> Result<(), InsertError> {
+        let mut parsed = Template::insertable(template)?;
+
+        #[cfg(feature = "tracing")]
+        tracing::trace!(template, parts = ?parsed.breakdown(), "inserting template");
+
+        if let Some(found) = self.root.conflict(&parsed.parts) {
+            if self.duplicates && *found.template == *template {
+                self.values[found.slot]
+                    .duplicates
+                    .get_or_insert_default()
+                    .push(data);
+                return Ok(());
+            }
+
+            let error = InsertError::Conflict {
+                existing: found.template.clone().into(),
+                witness: parsed.witness(),
+                location: found.location,
+            };
+
+            #[cfg(feature = "tracing")]
+            tracing::debug!(template, %error, "rejected template");
+
+            return Err(error);
+        }
+
+        self.compiler.insert(
+            &mut self.root,
+            &mut parsed,
+            Data {
+                slot: self.values.len(),
+                template: template.into(),
+                canonical: None,
+                location: Location::caller(),
+                hits: Counter::new(),
+            },
+        );
+
+        self.values.push(Value {
+            data,
+            duplicates: None,
+        });
+
+        Ok(())
+    }
+
+    fn found<'r, 'p>(
+        &'r self,
+        data: &'r Data,
+        path: &'p str,
+        parameters: Captures<'r, 'p>,
+    ) -> Match<'r, 'p, T> {
+        data.hits.hit();
+        Match::new(data, &self.values[data.slot], path, parameters)
+    }
+
+    /// Emits a trace event for a completed search.
+    #[cfg(feature = "tracing")]
+    fn trace(&self, path: &str, data: Option<&Data>) {
+        if let Some(data) = data {
+            tracing::trace!(path, template = &*data.template, "matched path");
+            return;
+        }
+
+        let bounds = &self.root.bounds;
+        let reason = if path.len() < bounds.shortest() || path.len() > bounds.longest() {
+            "path length out of bounds"
+        } else {
+            "no matching template"
+        };
+
+        tracing::trace!(path, reason, "unmatched path");
     }
 
     /// Searches for a matching template in the router.
@@ -66,13 +472,309 @@ impl<T> Router<T> {
     #[must_use]
     pub fn search<'r, 'p>(&'r self, path: &'p str) -> Option<Match<'r, 'p, T>> {
         let mut ctx = SearchContext::new();
-        let node = self.root.search(&mut ctx, path)?;
+        let node = self.root.search(&mut ctx, path);
+
+        #[cfg(feature = "tracing")]
+        self.trace(path, node);
+
+        let Some(node) = node else {
+            self.misses.hit();
+            return None;
+        };
+
+        Some(self.found(node, path, ctx.parameters))
+    }
+
+    /// Searches for a matching template in the router, capturing parameters into the given sink.
+    ///
+    /// Only the matched route is returned, since its parameters are held by the sink.
+    /// Pass `&mut ()` to skip capturing entirely, when only the matched template is needed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wayfind::RouterBuilder;
+    ///
+    /// let mut builder = RouterBuilder::new();
+    /// builder.insert("/users/<id>", 1)?;
+    ///
+    /// let router = builder.build();
+    ///
+    /// let route = router.search_into(&mut (), "/users/123").unwrap();
+    /// assert_eq!(route.template(), "/users/<id>");
+    ///
+    /// let mut parameters = vec![];
+    /// let route = router.search_into(&mut parameters, "/users/123").unwrap();
+    /// assert_eq!(route.data(), &1);
+    /// assert_eq!(parameters, [("id", "123")]);
+    /// # Ok::<_, Box<dyn core::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn search_into<'r, 'p, P: ParameterSink<'r, 'p>>(
+        &'r self,
+        parameters: &mut P,
+        path: &'p str,
+    ) -> Option<Route<'r, T>> {
+        let mut ctx = SearchContext::with_sink(parameters);
+        let node = self.root.search(&mut ctx, path);
+
+        #[cfg(feature = "tracing")]
+        self.trace(path, node);
+
+        let Some(node) = node else {
+            self.misses.hit();
+            return None;
+        };
+
+        node.hits.hit();
+        Some(Route::new(node, &self.values[node.slot]))
+    }
 
-        Some(Match {
-            data: &node.data,
-            template: &node.template,
-            parameters: ctx.parameters,
-        })
+    /// Searches for a matching template in the router, reusing the buffers of a scratch space.
+    ///
+    /// Behaves identically to [`Router::search`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wayfind::{RouterBuilder, SearchScratch};
+    ///
+    /// let mut builder = RouterBuilder::new();
+    /// builder.insert("/users/<id>", 1)?;
+    ///
+    /// let router = builder.build();
+    /// let mut scratch = SearchScratch::new();
+    ///
+    /// let search = router.search_with(&mut scratch, "/users/123").unwrap();
+    /// assert_eq!(search.data(), &1);
+    /// assert_eq!(search.parameters(), &[("id", "123")]);
+    ///
+    /// assert!(router.search_with(&mut scratch, "/not/found").is_none());
+    /// # Ok::<_, Box<dyn core::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn search_with<'r, 'p>(
+        &'r self,
+        scratch: &mut SearchScratch,
+        path: &'p str,
+    ) -> Option<Match<'r, 'p, T>> {
+        let mut ctx = SearchContext::from_scratch(scratch, Captures::new());
+        let node = self.root.search(&mut ctx, path);
+        let parameters = ctx.into_scratch(scratch);
+
+        #[cfg(feature = "tracing")]
+        self.trace(path, node);
+
+        let Some(node) = node else {
+            self.misses.hit();
+            return None;
+        };
+
+        Some(self.found(node, path, parameters))
+    }
+
+    /// Searches for a matching template in the router, reusing the buffers of a scratch space, and capturing parameters into the given sink.
+    ///
+    /// Combines [`Router::search_into`] and [`Router::search_with`].
+    /// With a reused sink, such as a cleared `Vec`, searches no longer allocate once every buffer has grown.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wayfind::{RouterBuilder, SearchScratch};
+    ///
+    /// let mut builder = RouterBuilder::new();
+    /// builder.insert("/<a>/<b>/<c>/<d>/<e>", 1)?;
+    ///
+    /// let router = builder.build();
+    /// let mut scratch = SearchScratch::new();
+    /// let mut parameters = Vec::new();
+    ///
+    /// for path in ["/1/2/3/4/5", "/6/7/8/9/10"] {
+    ///     parameters.clear();
+    ///     let route = router.search_into_with(&mut scratch, &mut parameters, path).unwrap();
+    ///     assert_eq!(route.data(), &1);
+    ///     assert_eq!(parameters.len(), 5);
+    /// }
+    /// # Ok::<_, Box<dyn core::error::Error>>(())
+    /// ```
+    pub fn search_into_with<'r, 'p, P: ParameterSink<'r, 'p>>(
+        &'r self,
+        scratch: &mut SearchScratch,
+        parameters: &mut P,
+        path: &'p str,
+    ) -> Option<Route<'r, T>> {
+        let mut ctx = SearchContext::from_scratch(scratch, parameters);
+        let node = self.root.search(&mut ctx, path);
+        ctx.into_scratch(scratch);
+
+        #[cfg(feature = "tracing")]
+        self.trace(path, node);
+
+        let Some(node) = node else {
+            self.misses.hit();
+            return None;
+        };
+
+        node.hits.hit();
+        Some(Route::new(node, &self.values[node.slot]))
+    }
+
+    /// Searches for a matching template, recording every step taken.
+    ///
+    /// Shows which children were tried, which were pruned and why, and which parameter values were captured.
+    /// Each node tries its children in priority order, backtracking to the next candidate when one fails, so the first match found wins.
+    /// The explanation also records where that decision was made, and how many earlier candidates failed there.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wayfind::{NodeKind, RouterBuilder};
+    ///
+    /// let mut builder = RouterBuilder::new();
+    /// builder.insert("/users/<id>", 1)?;
+    /// builder.insert("/<*catch_all>", 2)?;
+    ///
+    /// let router = builder.build();
+    /// let explanation = router.explain("/users/");
+    /// assert_eq!(explanation.template.as_deref(), Some("/<*catch_all>"));
+    ///
+    /// let decision = explanation.decision.unwrap();
+    /// assert_eq!(decision.kind, NodeKind::EndWildcard);
+    /// assert_eq!(decision.offset, 1);
+    /// # Ok::<_, Box<dyn core::error::Error>>(())
+    /// ```
+    #[cfg(feature = "explain")]
+    #[must_use]
+    pub fn explain(&self, path: &str) -> Explanation {
+        let mut ctx = SearchContext::new();
+        ctx.trace = Some(Vec::new());
+
+        let data = self.root.search(&mut ctx, path);
+
+        Explanation::new(
+            path,
+            data.map(|data| data.template.to_string()),
+            ctx.trace.unwrap_or_default(),
+        )
+    }
+
+    /// Size and shape statistics for this router.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wayfind::RouterBuilder;
+    ///
+    /// let mut builder = RouterBuilder::new();
+    /// builder.insert("/users/<id>", 1)?;
+    /// builder.insert("/users/<id>/files/<name>.pdf", 2)?;
+    ///
+    /// let router = builder.build();
+    /// let stats = router.stats();
+    /// assert_eq!(stats.templates, 2);
+    /// assert_eq!(stats.dynamic_nodes, 2);
+    /// assert_eq!(stats.inline_searches, 1);
+    /// # Ok::<_, Box<dyn core::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn stats(&self) -> RouterStats {
+        RouterStats::compute(&self.root, &self.values, &self.compiler)
+    }
+
+    /// Search counts for every template, alongside the number of unmatched searches.
+    ///
+    /// Every search counts, other than explained searches.
+    /// Counts are relaxed atomics, so searches running concurrently may not be reflected yet.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wayfind::RouterBuilder;
+    ///
+    /// let mut builder = RouterBuilder::new();
+    /// builder.insert("/users/<id>", 1)?;
+    /// builder.insert("/legacy/users/<id>", 2)?;
+    ///
+    /// let router = builder.build();
+    /// assert!(router.search("/users/123").is_some());
+    /// assert!(router.search("/not/found").is_none());
+    ///
+    /// let coverage = router.coverage();
+    /// assert_eq!(coverage.templates, [("/legacy/users/<id>", 0), ("/users/<id>", 1)]);
+    /// assert_eq!(coverage.misses, 1);
+    /// assert_eq!(coverage.unused().collect::<Vec<_>>(), ["/legacy/users/<id>"]);
+    /// # Ok::<_, Box<dyn core::error::Error>>(())
+    /// ```
+    #[cfg(feature = "coverage")]
+    #[must_use]
+    pub fn coverage(&self) -> Coverage<'_> {
+        Coverage::compute(&self.root, &self.misses)
+    }
+
+    /// A read-only view of the root of the search tree.
+    #[must_use]
+    pub fn root(&self) -> NodeRef<'_, T> {
+        NodeRef::new(&self.root, &self.values)
+    }
+
+    /// Walks every node of the search tree depth-first, in priority order.
+    ///
+    /// See [`Visitor`] for an example.
+    pub fn visit<'r, V: Visitor<'r, T>>(&'r self, visitor: &mut V) {
+        self.root().walk(visitor);
+    }
+
+    /// Renders the search tree in Graphviz DOT format.
+    ///
+    /// Each node is labeled with its kind and state.
+    /// Nodes that end a template are drawn with a double border, and labeled with the template.
+    /// Edges to parameter children are labeled with their search mode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wayfind::RouterBuilder;
+    ///
+    /// let mut builder = RouterBuilder::new();
+    /// builder.insert("/users/<id>", 1)?;
+    ///
+    /// let router = builder.build();
+    /// let dot = router.to_dot();
+    /// assert!(dot.starts_with("digraph router {"));
+    /// assert!(dot.contains(r#"[label="dynamic: <id>\n/users/<id>", peripheries=2]"#));
+    /// # Ok::<_, Box<dyn core::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn to_dot(&self) -> String {
+        let mut graph = Graph::new(Format::Dot);
+        self.visit(&mut graph);
+        graph.to_string()
+    }
+
+    /// Renders the search tree as a Mermaid flowchart.
+    ///
+    /// Labels match [`Router::to_dot`], with nodes that end a template drawn as stadiums.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wayfind::RouterBuilder;
+    ///
+    /// let mut builder = RouterBuilder::new();
+    /// builder.insert("/users/<id>", 1)?;
+    ///
+    /// let router = builder.build();
+    /// let mermaid = router.to_mermaid();
+    /// assert!(mermaid.starts_with("flowchart TD"));
+    /// assert!(mermaid.contains("-->|segment|"));
+    /// # Ok::<_, Box<dyn core::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn to_mermaid(&self) -> String {
+        let mut graph = Graph::new(Format::Mermaid);
+        self.visit(&mut graph);
+        graph.to_string()
     }
 }
 
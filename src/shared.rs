@@ -0,0 +1,111 @@
+use alloc::sync::Arc;
+use core::fmt;
+use core::ops::Deref;
+
+use arc_swap::{ArcSwap, Guard};
+
+use crate::router::Router;
+
+/// A [`Router`] handle shared between threads, which can be replaced while in use.
+///
+/// Readers take a [`Snapshot`] without locking, and keep searching it even after a newer router is published.
+/// Writers build a fresh router, then publish it atomically with [`SharedRouter::store`].
+///
+/// # Examples
+///
+/// ```rust
+/// use wayfind::{RouterBuilder, SharedRouter};
+///
+/// let mut builder = RouterBuilder::new();
+/// builder.insert("/users/<id>", 1)?;
+/// let shared = SharedRouter::new(builder.build());
+///
+/// let snapshot = shared.load();
+/// let search = snapshot.search("/users/123").unwrap();
+///
+/// let mut builder = RouterBuilder::new();
+/// builder.insert("/users/<id>", 2)?;
+/// shared.store(builder.build());
+///
+/// assert_eq!(search.data(), &1);
+/// assert_eq!(shared.load().search("/users/123").unwrap().data(), &2);
+/// # Ok::<_, Box<dyn core::error::Error>>(())
+/// ```
+pub struct SharedRouter<T> {
+    current: ArcSwap<Router<T>>,
+}
+
+impl<T> SharedRouter<T> {
+    /// Creates a new handle, publishing the given router.
+    #[must_use]
+    pub fn new(router: Router<T>) -> Self {
+        Self {
+            current: ArcSwap::from_pointee(router),
+        }
+    }
+
+    /// A snapshot of the currently published router.
+    ///
+    /// Snapshots are cheap to take, and are intended to be held for the duration of a single request.
+    /// For longer-lived access, use [`Snapshot::into_arc`].
+    #[must_use]
+    pub fn load(&self) -> Snapshot<T> {
+        Snapshot {
+            guard: self.current.load(),
+        }
+    }
+
+    /// Publishes a new router, for all future snapshots.
+    pub fn store(&self, router: Router<T>) {
+        self.current.store(Arc::new(router));
+    }
+
+    /// Publishes a new router, returning the previously published router.
+    ///
+    /// The previous router is only dropped once every snapshot of it is.
+    #[must_use = "use `SharedRouter::store` to discard the previous router"]
+    pub fn swap(&self, router: Router<T>) -> Arc<Router<T>> {
+        self.current.swap(Arc::new(router))
+    }
+}
+
+impl<T> From<Router<T>> for SharedRouter<T> {
+    fn from(value: Router<T>) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T> fmt::Debug for SharedRouter<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SharedRouter").finish_non_exhaustive()
+    }
+}
+
+/// A published [`Router`], kept alive for as long as the snapshot is.
+///
+/// Constructed by [`SharedRouter::load`].
+pub struct Snapshot<T> {
+    guard: Guard<Arc<Router<T>>>,
+}
+
+impl<T> Snapshot<T> {
+    /// Converts this snapshot into a reference-counted router.
+    #[must_use]
+    pub fn into_arc(self) -> Arc<Router<T>> {
+        Guard::into_inner(self.guard)
+    }
+}
+
+impl<T> Deref for Snapshot<T> {
+    type Target = Router<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.guard
+    }
+}
+
+impl<T> fmt::Debug for Snapshot<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Snapshot").finish_non_exhaustive()
+    }
+}
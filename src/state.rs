@@ -88,14 +88,14 @@ impl fmt::Display for WildcardState {
 
 /// An end-of-route catch-all wildcard.
 #[derive(Clone, Debug)]
-pub(crate) struct EndWildcardState<T> {
+pub(crate) struct EndWildcardState {
     pub name: Box<str>,
     /// Data is stored directly to avoid the need for the full `Node` machinery.
-    pub data: Data<T>,
+    pub data: Data,
 }
 
-impl<T> EndWildcardState<T> {
-    pub(crate) fn new(name: &str, data: Data<T>) -> Self {
+impl EndWildcardState {
+    pub(crate) fn new(name: &str, data: Data) -> Self {
         Self {
             name: name.into(),
             data,
@@ -103,7 +103,7 @@ impl<T> EndWildcardState<T> {
     }
 }
 
-impl<T> fmt::Display for EndWildcardState<T> {
+impl fmt::Display for EndWildcardState {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "<*{}>", self.name)
     }
@@ -0,0 +1,129 @@
+use crate::compiler::Compiler;
+use crate::node::{Data, Node, SearchMode, Value};
+use crate::state::RootState;
+
+/// Size and shape statistics for a compiled [`Router`](crate::Router).
+///
+/// Constructed by [`Router::stats`](crate::Router::stats).
+#[non_exhaustive]
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug, Default)]
+pub struct RouterStats {
+    /// The number of stored templates, including aliases.
+    pub templates: usize,
+
+    /// The number of static nodes.
+    pub static_nodes: usize,
+
+    /// The number of dynamic parameter nodes.
+    pub dynamic_nodes: usize,
+
+    /// The number of mid-route wildcard nodes.
+    pub wildcard_nodes: usize,
+
+    /// The number of end-of-route wildcards.
+    pub end_wildcard_nodes: usize,
+
+    /// The deepest node, counting the root as zero.
+    pub max_depth: usize,
+
+    /// The number of parameters whose failed boundaries are memoized during search.
+    pub revisitable_parameters: usize,
+
+    /// The number of nodes searching parameter children with [`SearchMode::Inline`], counted once per parameter kind.
+    pub inline_searches: usize,
+
+    /// The total number of reachability condition groups.
+    pub reachable_groups: usize,
+
+    /// The total number of pre-computed parameter suffixes.
+    pub suffixes: usize,
+
+    /// The estimated heap size of the router, in bytes.
+    ///
+    /// Includes the needle table kept for [`Router::insert`](crate::Router::insert).
+    /// Excludes any heap memory owned by the stored data itself.
+    pub heap_size: usize,
+}
+
+impl RouterStats {
+    pub(crate) fn compute<T>(
+        root: &Node<RootState>,
+        values: &[Value<T>],
+        compiler: &Compiler,
+    ) -> Self {
+        let mut stats = Self::default();
+        stats.visit(root, 0);
+
+        stats.heap_size += compiler.heap_size();
+
+        stats.heap_size += size_of_val(values);
+        for duplicates in values
+            .iter()
+            .filter_map(|value| value.duplicates.as_deref())
+        {
+            stats.heap_size += size_of_val(duplicates) + duplicates.capacity() * size_of::<T>();
+        }
+
+        stats
+    }
+
+    fn visit<S>(&mut self, node: &Node<S>, depth: usize) {
+        self.max_depth = self.max_depth.max(depth);
+
+        if let Some(data) = &node.data {
+            self.leaf(data);
+        }
+
+        self.reachable_groups += node.reachable.groups();
+        self.suffixes += node.suffixes.len();
+        self.heap_size += node.reachable.heap_size() + node.suffixes.heap_size();
+
+        self.heap_size += size_of_val(&*node.static_children)
+            + size_of_val(&*node.dynamic_children)
+            + size_of_val(&*node.wildcard_children);
+
+        if !node.dynamic_children.is_empty() && node.dynamic_search == SearchMode::Inline {
+            self.inline_searches += 1;
+        }
+
+        if !node.wildcard_children.is_empty() && node.wildcard_search == SearchMode::Inline {
+            self.inline_searches += 1;
+        }
+
+        for child in &node.static_children {
+            self.static_nodes += 1;
+            self.heap_size += child.state.prefix.len();
+            self.visit(child, depth + 1);
+        }
+
+        for child in &node.dynamic_children {
+            self.dynamic_nodes += 1;
+            self.revisitable_parameters += usize::from(child.state.id.is_some());
+            self.heap_size += child.state.name.len();
+            self.visit(child, depth + 1);
+        }
+
+        for child in &node.wildcard_children {
+            self.wildcard_nodes += 1;
+            self.revisitable_parameters += usize::from(child.state.id.is_some());
+            self.heap_size += child.state.name.len();
+            self.visit(child, depth + 1);
+        }
+
+        if let Some(child) = &node.end_wildcard {
+            self.end_wildcard_nodes += 1;
+            self.max_depth = self.max_depth.max(depth + 1);
+            self.heap_size += child.name.len();
+            self.leaf(&child.data);
+        }
+    }
+
+    fn leaf(&mut self, data: &Data) {
+        self.templates += 1;
+        self.heap_size += data.template.len()
+            + data
+                .canonical
+                .as_ref()
+                .map_or(0, |canonical| canonical.len());
+    }
+}
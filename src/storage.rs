@@ -116,6 +116,15 @@ impl<T: Copy, const N: usize> Storage<T, N> {
         }
     }
 
+    /// Removes all items, keeping any heap allocation for reuse.
+    pub(crate) fn clear(&mut self) {
+        match self {
+            Self::Empty => {}
+            Self::Inline(_, _) => *self = Self::Empty,
+            Self::Heap(items) => items.clear(),
+        }
+    }
+
     pub(crate) fn as_slice(&self) -> &[T] {
         match self {
             Self::Empty => &[],
@@ -180,6 +189,29 @@ mod tests {
         assert_eq!(storage.as_slice(), &[1]);
     }
 
+    #[test]
+    fn clear() {
+        let mut storage: Storage<u32, 2> = Storage::new();
+        storage.push(1);
+        storage.clear();
+        assert!(storage.as_slice().is_empty());
+
+        *storage.slot(1, 9).unwrap() = 5;
+        storage.clear();
+        assert_eq!(storage.get(1), None);
+
+        storage.push(1);
+        storage.push(2);
+        storage.push(3);
+        storage.clear();
+        assert!(matches!(&storage, Storage::Heap(items) if items.capacity() >= 3));
+        assert!(storage.as_slice().is_empty());
+
+        *storage.slot(1, 9).unwrap() = 5;
+        assert_eq!(storage.get(0), Some(&9));
+        assert_eq!(storage.get(1), Some(&5));
+    }
+
     #[test]
     fn slots() {
         let mut storage: Storage<u32, 2> = Storage::new();
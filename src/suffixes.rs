@@ -42,6 +42,22 @@ impl Suffixes {
         self.0.first().map_or(0, |suffix| suffix.bytes.len())
     }
 
+    /// The number of suffixes.
+    pub(crate) fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// The estimated heap size, in bytes.
+    ///
+    /// Each finder keeps its own copy of the suffix bytes.
+    pub(crate) fn heap_size(&self) -> usize {
+        self.0
+            .iter()
+            .map(|suffix| 2 * suffix.bytes.len())
+            .sum::<usize>()
+            + size_of_val(&*self.0)
+    }
+
     /// Whether the input starts with any suffix.
     pub(crate) fn accepts(&self, after: &[u8]) -> bool {
         self.0.iter().any(|suffix| {
@@ -73,8 +89,8 @@ impl Suffixes {
     }
 
     /// Computes the suffix set from a node's static descendants.
-    pub(crate) fn compute<S, T>(
-        node: &Node<S, T>,
+    pub(crate) fn compute<S>(
+        node: &Node<S>,
         prefix: &mut Vec<u8>,
         seen: &mut BTreeSet<Vec<u8>>,
     ) -> Self {
@@ -96,11 +112,7 @@ impl Suffixes {
     }
 
     /// Walks a static subtree, recording the accumulated prefix at each node that can terminate a route.
-    fn walk_static<T>(
-        node: &Node<StaticState, T>,
-        prefix: &mut Vec<u8>,
-        seen: &mut BTreeSet<Vec<u8>>,
-    ) {
+    fn walk_static(node: &Node<StaticState>, prefix: &mut Vec<u8>, seen: &mut BTreeSet<Vec<u8>>) {
         let start = prefix.len();
         prefix.extend_from_slice(&node.state.prefix);
 
@@ -0,0 +1,263 @@
+use core::fmt;
+
+use crate::bounds::Bounds;
+use crate::node::{Data, Node, SearchMode, Value};
+use crate::state::{DynamicState, EndWildcardState, RootState, StaticState, WildcardState};
+
+/// The kind of a node in the search tree.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub enum NodeKind {
+    /// The root of the tree.
+    Root,
+
+    /// A static byte prefix.
+    Static,
+
+    /// A dynamic parameter.
+    Dynamic,
+
+    /// A mid-route wildcard parameter.
+    Wildcard,
+
+    /// An end-of-route catch-all wildcard.
+    EndWildcard,
+}
+
+impl fmt::Display for NodeKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Root => write!(f, "root"),
+            Self::Static => write!(f, "static"),
+            Self::Dynamic => write!(f, "dynamic"),
+            Self::Wildcard => write!(f, "wildcard"),
+            Self::EndWildcard => write!(f, "end wildcard"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Inner<'r> {
+    Root(&'r Node<RootState>),
+    Static(&'r Node<StaticState>),
+    Dynamic(&'r Node<DynamicState>),
+    Wildcard(&'r Node<WildcardState>),
+    EndWildcard(&'r EndWildcardState),
+}
+
+/// Evaluates an expression against the underlying node, or a fallback for end wildcards.
+macro_rules! with_node {
+    ($inner:expr, $node:ident => $body:expr, $end:pat => $fallback:expr) => {
+        match $inner {
+            Inner::Root($node) => $body,
+            Inner::Static($node) => $body,
+            Inner::Dynamic($node) => $body,
+            Inner::Wildcard($node) => $body,
+            Inner::EndWildcard($end) => $fallback,
+        }
+    };
+}
+
+/// A read-only view of a node in a compiled [`Router`](crate::Router).
+///
+/// Constructed by [`Router::root`](crate::Router::root).
+///
+/// # Examples
+///
+/// ```rust
+/// use wayfind::{NodeKind, RouterBuilder};
+///
+/// let mut builder = RouterBuilder::new();
+/// builder.insert("/users/<id>", 1)?;
+///
+/// let router = builder.build();
+/// let users = router.root().children().next().unwrap();
+/// assert_eq!(users.kind(), NodeKind::Static);
+/// assert_eq!(users.prefix(), Some(&b"/users/"[..]));
+///
+/// let id = users.children().next().unwrap();
+/// assert_eq!(id.kind(), NodeKind::Dynamic);
+/// assert_eq!(id.name(), Some("id"));
+/// assert_eq!(id.template(), Some("/users/<id>"));
+/// assert_eq!(id.data(), Some(&1));
+/// # Ok::<_, Box<dyn core::error::Error>>(())
+/// ```
+#[derive(Debug)]
+pub struct NodeRef<'r, T> {
+    inner: Inner<'r>,
+    values: &'r [Value<T>],
+}
+
+impl<T> Clone for NodeRef<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for NodeRef<'_, T> {}
+
+impl<'r, T> NodeRef<'r, T> {
+    pub(crate) const fn new(root: &'r Node<RootState>, values: &'r [Value<T>]) -> Self {
+        Self {
+            inner: Inner::Root(root),
+            values,
+        }
+    }
+
+    /// The kind of this node.
+    #[must_use]
+    pub const fn kind(&self) -> NodeKind {
+        match self.inner {
+            Inner::Root(_) => NodeKind::Root,
+            Inner::Static(_) => NodeKind::Static,
+            Inner::Dynamic(_) => NodeKind::Dynamic,
+            Inner::Wildcard(_) => NodeKind::Wildcard,
+            Inner::EndWildcard(_) => NodeKind::EndWildcard,
+        }
+    }
+
+    /// The prefix bytes of a static node.
+    ///
+    /// May not be valid UTF-8, since prefixes can be split within a multibyte character.
+    #[must_use]
+    pub fn prefix(&self) -> Option<&'r [u8]> {
+        match self.inner {
+            Inner::Static(node) => Some(&node.state.prefix),
+            Inner::Root(_) | Inner::Dynamic(_) | Inner::Wildcard(_) | Inner::EndWildcard(_) => None,
+        }
+    }
+
+    /// The parameter name of a dynamic or wildcard node.
+    #[must_use]
+    pub fn name(&self) -> Option<&'r str> {
+        match self.inner {
+            Inner::Dynamic(node) => Some(&node.state.name),
+            Inner::Wildcard(node) => Some(&node.state.name),
+            Inner::EndWildcard(state) => Some(&state.name),
+            Inner::Root(_) | Inner::Static(_) => None,
+        }
+    }
+
+    const fn leaf(&self) -> Option<&'r Data> {
+        with_node!(self.inner, node => node.data.as_ref(), state => Some(&state.data))
+    }
+
+    /// The data of the template ending at this node, if any.
+    #[must_use]
+    pub fn data(&self) -> Option<&'r T> {
+        let leaf = self.leaf()?;
+        self.values.get(leaf.slot).map(|value| &value.data)
+    }
+
+    /// The template ending at this node, if any.
+    #[must_use]
+    pub fn template(&self) -> Option<&'r str> {
+        self.leaf().map(|leaf| &*leaf.template)
+    }
+
+    /// The template that the template ending at this node is an alias of, if any.
+    ///
+    /// See [`Match::canonical_template`](crate::Match::canonical_template).
+    #[must_use]
+    pub fn canonical_template(&self) -> Option<&'r str> {
+        self.leaf()
+            .map(|leaf| leaf.canonical.as_deref().unwrap_or(&leaf.template))
+    }
+
+    /// The path length bounds of this node's subtree.
+    ///
+    /// End wildcards have no bounds, as they match any non-empty remainder.
+    #[must_use]
+    pub const fn bounds(&self) -> Option<&'r Bounds> {
+        with_node!(self.inner, node => Some(&node.bounds), _ => None)
+    }
+
+    /// How this node searches its dynamic children.
+    #[must_use]
+    pub const fn dynamic_search(&self) -> Option<SearchMode> {
+        with_node!(self.inner, node => Some(node.dynamic_search), _ => None)
+    }
+
+    /// How this node searches its wildcard children.
+    #[must_use]
+    pub const fn wildcard_search(&self) -> Option<SearchMode> {
+        with_node!(self.inner, node => Some(node.wildcard_search), _ => None)
+    }
+
+    /// The children of this node, in priority order.
+    ///
+    /// Static children come first, followed by dynamic children, wildcard children, then the end wildcard.
+    pub fn children(&self) -> impl Iterator<Item = Self> + use<'r, T> {
+        let (statics, dynamics, wildcards, end) = with_node!(
+            self.inner,
+            node => (
+                &*node.static_children,
+                &*node.dynamic_children,
+                &*node.wildcard_children,
+                node.end_wildcard.as_ref(),
+            ),
+            _ => (&[][..], &[][..], &[][..], None)
+        );
+
+        let values = self.values;
+        statics
+            .iter()
+            .map(Inner::Static)
+            .chain(dynamics.iter().map(Inner::Dynamic))
+            .chain(wildcards.iter().map(Inner::Wildcard))
+            .chain(end.map(Inner::EndWildcard))
+            .map(move |inner| Self { inner, values })
+    }
+
+    /// Walks this node and its descendants depth-first, in priority order.
+    pub fn walk<V: Visitor<'r, T>>(self, visitor: &mut V) {
+        if visitor.enter(self) {
+            for child in self.children() {
+                child.walk(visitor);
+            }
+        }
+
+        visitor.leave(self);
+    }
+}
+
+/// Visits the nodes of a compiled [`Router`](crate::Router).
+///
+/// Used with [`Router::visit`](crate::Router::visit) or [`NodeRef::walk`].
+///
+/// # Examples
+///
+/// ```rust
+/// use wayfind::{NodeRef, RouterBuilder, Visitor};
+///
+/// #[derive(Default)]
+/// struct Templates(Vec<String>);
+///
+/// impl<'r, T> Visitor<'r, T> for Templates {
+///     fn enter(&mut self, node: NodeRef<'r, T>) -> bool {
+///         if let Some(template) = node.template() {
+///             self.0.push(template.to_owned());
+///         }
+///
+///         true
+///     }
+/// }
+///
+/// let mut builder = RouterBuilder::new();
+/// builder.insert("/users/<id>", 1)?;
+/// builder.insert("/users", 2)?;
+///
+/// let router = builder.build();
+/// let mut templates = Templates::default();
+/// router.visit(&mut templates);
+/// assert_eq!(templates.0, ["/users", "/users/<id>"]);
+/// # Ok::<_, Box<dyn core::error::Error>>(())
+/// ```
+pub trait Visitor<'r, T> {
+    /// Called before visiting the children of a node.
+    ///
+    /// Returning `false` skips the children.
+    fn enter(&mut self, node: NodeRef<'r, T>) -> bool;
+
+    /// Called after visiting the children of a node, or after skipping them.
+    fn leave(&mut self, _node: NodeRef<'r, T>) {}
+}
@@ -0,0 +1,105 @@
+#![expect(missing_docs, reason = "Tests")]
+
+use core::error::Error;
+
+use similar_asserts::assert_eq;
+use wayfind::{InsertError, RouterBuilder};
+
+mod common;
+use common::redact;
+
+#[test]
+fn aliases_shared_data() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/users/<id>", vec!["get"])?;
+    builder.insert("/files/<*path>", vec!["download"])?;
+    builder.alias("/u/<id>", "/users/<id>")?;
+    builder.alias("/members/<id>", "/u/<id>")?;
+    builder.alias("/f/<*path>", "/files/<*path>")?;
+
+    builder.upsert("/u/<id>", vec!["get", "delete"])?;
+
+    let router = builder.build();
+    insta::assert_snapshot!(router, @r"
+    /
+    ├─ f
+    │  ├─ /
+    │  │  ╰─ <*path>
+    │  ╰─ iles/
+    │     ╰─ <*path>
+    ├─ members/
+    │  ╰─ <id>
+    ╰─ u
+       ├─ /
+       │  ╰─ <id>
+       ╰─ sers/
+          ╰─ <id>
+    ");
+
+    let search = router.search("/users/123").unwrap();
+    assert_eq!(search.data(), &vec!["get", "delete"]);
+    assert_eq!(search.template(), "/users/<id>");
+    assert_eq!(search.canonical_template(), "/users/<id>");
+
+    let search = router.search("/u/123").unwrap();
+    assert_eq!(search.data(), &vec!["get", "delete"]);
+    assert_eq!(search.template(), "/u/<id>");
+    assert_eq!(search.canonical_template(), "/users/<id>");
+    assert_eq!(search.parameters(), &[("id", "123")]);
+
+    let search = router.search("/members/123").unwrap();
+    assert_eq!(search.template(), "/members/<id>");
+    assert_eq!(search.canonical_template(), "/users/<id>");
+
+    let search = router.search("/f/a/b.txt").unwrap();
+    assert_eq!(search.data(), &vec!["download"]);
+    assert_eq!(search.template(), "/f/<*path>");
+    assert_eq!(search.canonical_template(), "/files/<*path>");
+
+    Ok(())
+}
+
+#[test]
+fn aliases_parameter_order() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/users/<user>/posts/<post>", 1)?;
+    builder.alias("/posts/<post>/by/<user>", "/users/<user>/posts/<post>")?;
+
+    let router = builder.build();
+    let search = router.search("/posts/2/by/1").unwrap();
+    assert_eq!(search.data(), &1);
+    assert_eq!(search.parameters(), &[("post", "2"), ("user", "1")]);
+
+    Ok(())
+}
+
+#[test]
+fn aliases_errors() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/users/<id>", 1)?;
+
+    let error = builder.alias("/u/<id>", "/users/<name>").unwrap_err();
+    assert_eq!(
+        error,
+        InsertError::UnknownTemplate {
+            template: "/users/<name>".to_owned(),
+        }
+    );
+
+    let error = builder.alias("/u/<name>", "/users/<id>").unwrap_err();
+    insta::assert_snapshot!(error, @"alias `/u/<name>` has different parameters than `/users/<id>`");
+
+    let error = builder.alias("/u", "/users/<id>").unwrap_err();
+    insta::assert_snapshot!(error, @"alias `/u` has different parameters than `/users/<id>`");
+
+    let error = builder.alias("/users/<id>", "/users/<id>").unwrap_err();
+    insta::assert_snapshot!(redact(&error), @"conflicts with `/users/<id>` (inserted at [location]), both match `/users/x`");
+
+    let error = builder.alias("/u/<id", "/users/<id>").unwrap_err();
+    insta::assert_snapshot!(error, @"unbalanced angle bracket");
+
+    let router = builder.build();
+    assert_eq!(router.search("/u/123").map(|search| *search.data()), None);
+
+    Ok(())
+}
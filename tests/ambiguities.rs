@@ -0,0 +1,84 @@
+#![expect(missing_docs, reason = "Tests")]
+
+use core::error::Error;
+
+use similar_asserts::assert_eq;
+use wayfind::{Ambiguity, RouterBuilder};
+
+#[test]
+fn ambiguities_static_dynamic() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/users/<id>", 1)?;
+    builder.insert("/users/me", 2)?;
+    builder.insert("/posts/<id>", 3)?;
+
+    let ambiguities = builder.ambiguities();
+    assert_eq!(
+        ambiguities,
+        vec![Ambiguity {
+            first: "/users/me".to_owned(),
+            second: "/users/<id>".to_owned(),
+            witness: "/users/me".to_owned(),
+            winner: "/users/me".to_owned(),
+        }]
+    );
+
+    insta::assert_snapshot!(ambiguities[0], @"`/users/me` and `/users/<id>` both match `/users/me`, `/users/me` wins");
+
+    Ok(())
+}
+
+#[test]
+fn ambiguities_wildcard() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/files/<*path>", 1)?;
+    builder.insert("/files/<name>.pdf", 2)?;
+    builder.insert("/files/<*path>/edit", 3)?;
+
+    let ambiguities: Vec<String> = builder
+        .ambiguities()
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+
+    insta::assert_snapshot!(ambiguities.join("\n"), @r"
+    `/files/<name>.pdf` and `/files/<*path>` both match `/files/x.pdf`, `/files/<name>.pdf` wins
+    `/files/<*path>/edit` and `/files/<*path>` both match `/files/x/edit`, `/files/<*path>/edit` wins
+    ");
+
+    Ok(())
+}
+
+#[test]
+fn ambiguities_third_winner() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/<a>/x", 1)?;
+    builder.insert("/x/<b>", 2)?;
+    builder.insert("/x/x", 3)?;
+
+    let ambiguities: Vec<String> = builder
+        .ambiguities()
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+
+    insta::assert_snapshot!(ambiguities.join("\n"), @r"
+    `/x/x` and `/x/<b>` both match `/x/x`, `/x/x` wins
+    `/x/x` and `/<a>/x` both match `/x/x`, `/x/x` wins
+    `/x/<b>` and `/<a>/x` both match `/x/x`, `/x/x` wins
+    ");
+
+    Ok(())
+}
+
+#[test]
+fn ambiguities_none() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/users/<id>", 1)?;
+    builder.insert("/users/<id>/posts", 2)?;
+    builder.insert("/static/<*path>.css", 3)?;
+
+    assert_eq!(builder.ambiguities(), vec![]);
+
+    Ok(())
+}
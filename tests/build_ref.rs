@@ -0,0 +1,39 @@
+#![expect(missing_docs, reason = "Tests")]
+
+use core::error::Error;
+
+use similar_asserts::assert_eq;
+use wayfind::RouterBuilder;
+
+#[test]
+fn build_ref_keeps_builder() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new().with_duplicates();
+    builder.insert("/users/<id>", "get")?;
+    builder.insert("/users/<id>", "delete")?;
+    builder.alias("/u/<id>", "/users/<id>")?;
+
+    let first = builder.build_ref();
+    builder.insert("/posts/<id>", "post")?;
+    builder.upsert("/users/<id>", "put")?;
+
+    let second = builder.build_ref();
+    let last = builder.build();
+
+    let search = first.search("/u/123").unwrap();
+    assert_eq!(search.values().collect::<Vec<_>>(), [&"get", &"delete"]);
+    assert_eq!(first.search("/posts/1").map(|search| *search.data()), None);
+
+    for router in [&second, &last] {
+        let search = router.search("/u/123").unwrap();
+        assert_eq!(search.data(), &"put");
+        assert_eq!(
+            router.search("/posts/1").map(|search| *search.data()),
+            Some("post")
+        );
+    }
+
+    assert_eq!(second.to_string(), last.to_string());
+    assert_eq!(second.to_dot(), last.to_dot());
+
+    Ok(())
+}
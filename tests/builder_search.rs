@@ -0,0 +1,155 @@
+#![expect(missing_docs, reason = "Tests")]
+
+use core::error::Error;
+
+use similar_asserts::assert_eq;
+use wayfind::{Match, RouterBuilder};
+
+#[path = "../benches/fixtures/gitlab_routes.rs"]
+mod gitlab_routes;
+
+fn describe(search: Option<Match<'_, '_, usize>>) -> String {
+    match search {
+        Some(search) => format!("{} {:?}", search.template(), search.parameters()),
+        None => "no match".to_owned(),
+    }
+}
+
+/// A small deterministic generator, so failures are reproducible.
+struct Generator(u64);
+
+impl Generator {
+    fn pick<'a>(&mut self, items: &[&'a str]) -> &'a str {
+        self.0 = self
+            .0
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+
+        let index = usize::try_from(self.0 >> 33).unwrap_or_default() % items.len();
+        items[index]
+    }
+
+    fn join(&mut self, start: &str, items: &[&str], length: usize) -> String {
+        let mut joined = start.to_owned();
+        for _ in 0..length {
+            joined.push_str(self.pick(items));
+        }
+
+        joined
+    }
+}
+
+#[test]
+fn builder_search() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/users/<id>", 1)?;
+    builder.insert("/users/<id>.<ext>", 2)?;
+    builder.insert("/users/me", 3)?;
+    builder.insert("/files/<*path>/edit", 4)?;
+    builder.insert("/<*catch_all>", 5)?;
+
+    let search = builder.search("/users/123.json").unwrap();
+    assert_eq!(search.data(), &2);
+    assert_eq!(search.template(), "/users/<id>.<ext>");
+    assert_eq!(search.parameters(), &[("id", "123"), ("ext", "json")]);
+
+    let search = builder.search("/files/a/b/edit").unwrap();
+    assert_eq!(search.parameters(), &[("path", "a/b")]);
+
+    let search = builder.search("/users/").unwrap();
+    assert_eq!(search.template(), "/<*catch_all>");
+
+    assert_eq!(
+        builder.search("/users/me").map(|search| *search.data()),
+        Some(3)
+    );
+    assert_eq!(builder.search("").map(|search| *search.data()), None);
+
+    Ok(())
+}
+
+#[test]
+fn builder_search_gitlab() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    for (index, route) in gitlab_routes::routes().iter().enumerate() {
+        builder.insert(route, index)?;
+    }
+
+    let router = builder.build_ref();
+    let mut generator = Generator(0);
+
+    for route in gitlab_routes::routes() {
+        let mut path = String::new();
+        for part in route.split_inclusive(['<', '>']) {
+            match part.strip_suffix('>') {
+                Some(parameter) if parameter.starts_with('*') => {
+                    path.push_str(generator.pick(&["a/b", "a", "a.json", "-/a"]));
+                }
+                Some(_) => path.push_str(generator.pick(&["x", "x.json", "-", "1"])),
+                None => path.push_str(part.trim_end_matches('<')),
+            }
+        }
+
+        for path in [
+            path.clone(),
+            format!("{path}/extra"),
+            format!("{path}.json"),
+        ] {
+            assert_eq!(
+                describe(builder.search(&path)),
+                describe(router.search(&path)),
+                "{path}"
+            );
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn builder_search_generated() {
+    const TEMPLATE: &[&str] = &[
+        "/", "/", "a", "b", ".", "-", "<p>", "<q>", "<*w>", "/x", ".json",
+    ];
+    const PATH: &[&str] = &["/", "/", "a", "b", ".", "-", "x", "json", "é"];
+
+    let mut generator = Generator(42);
+    for _ in 0..200 {
+        let mut builder = RouterBuilder::new();
+        for index in 0..8 {
+            let template = generator.join("/", TEMPLATE, index % 5 + 1);
+            builder.insert(&template, index).unwrap_or_default();
+        }
+
+        let router = builder.build_ref();
+        for length in 0..50 {
+            let path = generator.join("/", PATH, length % 8);
+            assert_eq!(
+                describe(builder.search(&path)),
+                describe(router.search(&path)),
+                "{path}\n{router}"
+            );
+        }
+    }
+}
+
+#[test]
+fn builder_search_backtracking() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/<a>-<b>-<c>-<d>-<e>/x", 1)?;
+
+    let router = builder.build_ref();
+
+    for path in [
+        format!("/{}", "-".repeat(40)),
+        format!("/{}/x", "-".repeat(40)),
+    ] {
+        assert_eq!(
+            describe(builder.search(&path)),
+            describe(router.search(&path)),
+            "{path}"
+        );
+    }
+
+    Ok(())
+}
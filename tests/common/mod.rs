@@ -0,0 +1,24 @@
+//! Helpers shared between integration tests.
+
+use core::fmt::Display;
+
+/// Hides the call sites recorded in conflicts, so snapshots don't depend on line numbers.
+///
+/// Tests that care about the call site compare the error's `location` field directly.
+pub(crate) fn redact<M: Display>(message: &M) -> String {
+    const MARKER: &str = "(inserted at ";
+
+    let message = message.to_string();
+    let mut redacted = String::new();
+    let mut rest = message.as_str();
+
+    while let Some(start) = rest.find(MARKER) {
+        let (before, after) = rest.split_at(start + MARKER.len());
+        redacted.push_str(before);
+        redacted.push_str("[location]");
+        rest = after.find(')').map_or("", |end| &after[end..]);
+    }
+
+    redacted.push_str(rest);
+    redacted
+}
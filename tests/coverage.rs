@@ -0,0 +1,61 @@
+#![expect(missing_docs, reason = "Tests")]
+
+use core::error::Error;
+
+use similar_asserts::assert_eq;
+use wayfind::{RouterBuilder, SearchScratch};
+
+#[test]
+fn coverage_counts() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/users/<id>", 1)?;
+    builder.insert("/users/me", 2)?;
+    builder.insert("/files/<*path>", 3)?;
+    builder.alias("/u/<id>", "/users/<id>")?;
+
+    let router = builder.build();
+    let coverage = router.coverage();
+    assert_eq!(coverage.searches(), 0);
+    assert_eq!(
+        coverage.unused().collect::<Vec<_>>(),
+        ["/files/<*path>", "/u/<id>", "/users/me", "/users/<id>"]
+    );
+
+    let hit = |path| router.search(path).map(|search| *search.data());
+    assert_eq!(hit("/users/123"), Some(1));
+    assert_eq!(hit("/users/456"), Some(1));
+    assert_eq!(hit("/users/me"), Some(2));
+    assert_eq!(hit("/u/123"), Some(1));
+    assert_eq!(hit("/"), None);
+
+    let route = router.search_into(&mut (), "/users/789");
+    assert_eq!(route.map(|route| *route.data()), Some(1));
+
+    let search = router.search_with(&mut SearchScratch::new(), "/missing");
+    assert_eq!(search.map(|search| *search.data()), None);
+
+    let explanation = router.explain("/users/123");
+    assert_eq!(explanation.template.as_deref(), Some("/users/<id>"));
+
+    let coverage = router.coverage();
+    assert_eq!(
+        coverage.templates,
+        [
+            ("/files/<*path>", 0),
+            ("/u/<id>", 1),
+            ("/users/me", 1),
+            ("/users/<id>", 3),
+        ]
+    );
+    assert_eq!(coverage.misses, 2);
+    assert_eq!(coverage.searches(), 7);
+    assert_eq!(coverage.unused().collect::<Vec<_>>(), ["/files/<*path>"]);
+
+    let clone = router.clone();
+    let search = clone.search("/files/a.txt");
+    assert_eq!(search.map(|search| *search.data()), Some(3));
+    assert_eq!(clone.coverage().unused().count(), 0);
+    assert_eq!(router.coverage().unused().count(), 1);
+
+    Ok(())
+}
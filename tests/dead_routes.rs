@@ -0,0 +1,148 @@
+#![expect(missing_docs, reason = "Tests")]
+
+use core::error::Error;
+
+use similar_asserts::assert_eq;
+use wayfind::{DeadRoute, RouterBuilder};
+
+#[path = "../benches/fixtures/gitlab_routes.rs"]
+mod gitlab_routes;
+
+#[test]
+fn dead_routes_readme() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/api/<version>/<*rest>", 1)?;
+    builder.insert("/api/<*path>/help", 2)?;
+
+    // Parameters can't be empty, so an empty segment skips the first template.
+    assert_eq!(builder.suspected_dead_routes(), vec![]);
+
+    let router = builder.build();
+    let search = router.search("/api/docs/help").unwrap();
+    assert_eq!(search.data(), &1);
+
+    let search = router.search("/api//docs/help").unwrap();
+    assert_eq!(search.data(), &2);
+
+    Ok(())
+}
+
+#[test]
+fn dead_routes_greedy_wildcard() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/files/<*path>/", 1)?;
+    builder.insert("/files/<*path>/edit/", 2)?;
+
+    let dead = builder.suspected_dead_routes();
+    assert_eq!(
+        dead,
+        vec![DeadRoute {
+            template: "/files/<*path>/edit/".to_owned(),
+            witness: "/files/x/edit/".to_owned(),
+            winner: Some("/files/<*path>/".to_owned()),
+        }]
+    );
+
+    insta::assert_snapshot!(dead[0], @"`/files/<*path>/edit/` is likely never matched, `/files/x/edit/` goes to `/files/<*path>/`");
+
+    let router = builder.build();
+    let search = router.search("/files/docs/edit/").unwrap();
+    assert_eq!(search.data(), &1);
+
+    Ok(())
+}
+
+#[test]
+fn dead_routes_shadowed_wildcard() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/<*path>/", 1)?;
+    builder.insert("/<*path>/x/", 2)?;
+    builder.insert("/<*path>/y/<id>/", 3)?;
+
+    let dead: Vec<String> = builder
+        .suspected_dead_routes()
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+
+    insta::assert_snapshot!(dead.join("\n"), @r"
+    `/<*path>/x/` is likely never matched, `/x/x/` goes to `/<*path>/`
+    `/<*path>/y/<id>/` is likely never matched, `/x/y/x/` goes to `/<*path>/`
+    ");
+
+    Ok(())
+}
+
+#[test]
+fn dead_routes_reachable() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/users/<id>", 1)?;
+    builder.insert("/users/me", 2)?;
+    builder.insert("/<*path>.json", 3)?;
+    builder.insert("/files/<*path>", 4)?;
+    builder.insert("/files/<name>.pdf", 5)?;
+
+    assert_eq!(builder.suspected_dead_routes(), vec![]);
+
+    Ok(())
+}
+
+#[test]
+fn dead_routes_filler_static() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/<a>", 1)?;
+    builder.insert("/x", 2)?;
+    builder.insert("/<*path>/x", 3)?;
+    builder.insert("/<b>/<c>", 4)?;
+    builder.insert("/<b>/<c>/<d>", 5)?;
+
+    assert_eq!(builder.suspected_dead_routes(), vec![]);
+
+    let router = builder.build();
+    assert_eq!(router.search("/foo").unwrap().data(), &1);
+    assert_eq!(router.search("/foo/bar/x").unwrap().data(), &5);
+    assert_eq!(router.search("/foo/bar/baz/x").unwrap().data(), &3);
+
+    Ok(())
+}
+
+#[test]
+fn dead_routes_trailing_slash() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/<name>", 1)?;
+    builder.insert("/<*dir>/<file>", 2)?;
+    builder.insert("/<*path>", 3)?;
+
+    assert_eq!(builder.suspected_dead_routes(), vec![]);
+
+    let router = builder.build();
+    assert_eq!(router.search("/docs/").unwrap().data(), &3);
+
+    Ok(())
+}
+
+#[test]
+fn dead_routes_gitlab() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    for (index, route) in gitlab_routes::routes().iter().enumerate() {
+        builder.insert(route, index)?;
+    }
+
+    // Mostly templates behind a wildcard, which greedily claims the rest of the path.
+    let dead = builder.suspected_dead_routes();
+    assert_eq!(dead.len(), 1594);
+    let winner = dead
+        .iter()
+        .find(|dead| {
+            dead.template
+                == "/-/push_from_secondary/<geo_node_id>/<*repository_path>/git-receive-pack/"
+        })
+        .and_then(|dead| dead.winner.as_deref());
+
+    assert_eq!(
+        winner,
+        Some("/-/push_from_secondary/<geo_node_id>/<*repository_path>/")
+    );
+
+    Ok(())
+}
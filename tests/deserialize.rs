@@ -0,0 +1,211 @@
+#![expect(missing_docs, reason = "Tests")]
+
+use core::error::Error;
+
+use serde::Deserialize;
+use similar_asserts::assert_eq;
+use wayfind::{DeserializeError, RouterBuilder};
+
+#[derive(Deserialize, PartialEq, Debug)]
+struct User<'p> {
+    id: u32,
+    name: &'p str,
+}
+
+#[derive(Deserialize, PartialEq, Debug)]
+struct File<'p> {
+    id: u32,
+    #[serde(borrow)]
+    path: Vec<&'p str>,
+}
+
+#[derive(Deserialize, PartialEq, Debug)]
+#[serde(rename_all = "lowercase")]
+enum Format {
+    Json,
+    Xml,
+}
+
+#[test]
+fn deserialize_struct() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/users/<id>/<name>", 1)?;
+
+    let router = builder.build();
+
+    let search = router.search("/users/123/alice").unwrap();
+    let user: User<'_> = search.deserialize()?;
+    assert_eq!(
+        user,
+        User {
+            id: 123,
+            name: "alice",
+        }
+    );
+
+    Ok(())
+}
+
+#[test]
+fn deserialize_tuple() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/<year>/<month>/<day>", 1)?;
+
+    let router = builder.build();
+
+    let search = router.search("/2024/12/01").unwrap();
+    let date: (u16, u8, u8) = search.deserialize()?;
+    assert_eq!(date, (2024, 12, 1));
+
+    let error = search.deserialize::<(u16, u8)>().unwrap_err();
+    assert_eq!(
+        error,
+        DeserializeError::InvalidLength {
+            expected: 2,
+            found: 3,
+        }
+    );
+
+    insta::assert_snapshot!(error, @"expected 2 parameter(s), found 3");
+
+    Ok(())
+}
+
+#[test]
+fn deserialize_single() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/users/<id>", 1)?;
+    builder.insert("/export.<format>", 2)?;
+    builder.insert("/", 3)?;
+
+    let router = builder.build();
+
+    let search = router.search("/users/123").unwrap();
+    let id: u64 = search.deserialize()?;
+    assert_eq!(id, 123);
+
+    let search = router.search("/export.xml").unwrap();
+    let format: Format = search.deserialize()?;
+    assert_eq!(format, Format::Xml);
+
+    let search = router.search("/").unwrap();
+    let id: Option<u64> = search.deserialize()?;
+    assert_eq!(id, None);
+
+    let error = search.deserialize::<u64>().unwrap_err();
+    insta::assert_snapshot!(error, @"expected 1 parameter(s), found 0");
+
+    Ok(())
+}
+
+#[test]
+fn deserialize_wildcard() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/files/<*path>", 1)?;
+
+    let router = builder.build();
+
+    let search = router
+        .search("/files/documents/reports/annual.pdf")
+        .unwrap();
+
+    let path: String = search.deserialize()?;
+    assert_eq!(path, "documents/reports/annual.pdf");
+
+    let segments: Vec<String> = search.deserialize()?;
+    assert_eq!(segments, ["documents", "reports", "annual.pdf"]);
+
+    let (path,): (String,) = search.deserialize()?;
+    assert_eq!(path, "documents/reports/annual.pdf");
+
+    Ok(())
+}
+
+#[derive(Deserialize, PartialEq, Debug)]
+struct Range {
+    range: (u32, u32),
+}
+
+#[test]
+fn deserialize_wildcard_tuple() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/lines/<*range>", 1)?;
+
+    let router = builder.build();
+
+    let search = router.search("/lines/10/20").unwrap();
+    let range: Range = search.deserialize()?;
+    assert_eq!(range, Range { range: (10, 20) });
+
+    let search = router.search("/lines/10/20/30").unwrap();
+    let error = search.deserialize::<Range>().unwrap_err();
+    insta::assert_snapshot!(error, @"parameter `range`: invalid length 3, expected a tuple of size 2");
+
+    let search = router.search("/lines/10").unwrap();
+    let error = search.deserialize::<Range>().unwrap_err();
+    insta::assert_snapshot!(error, @"parameter `range`: invalid length 1, expected a tuple of size 2");
+
+    Ok(())
+}
+
+#[test]
+fn deserialize_wildcard_field() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/users/<id>/files/<*path>", 1)?;
+
+    let router = builder.build();
+
+    let search = router.search("/users/123/files/docs/report.pdf").unwrap();
+    let file: File<'_> = search.deserialize()?;
+    assert_eq!(
+        file,
+        File {
+            id: 123,
+            path: vec!["docs", "report.pdf"],
+        }
+    );
+
+    Ok(())
+}
+
+#[test]
+fn deserialize_invalid_value() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/users/<id>/<name>", 1)?;
+    builder.insert("/export.<format>", 2)?;
+
+    let router = builder.build();
+
+    let search = router.search("/users/abc/alice").unwrap();
+    let error = search.deserialize::<User<'_>>().unwrap_err();
+    assert_eq!(
+        error,
+        DeserializeError::InvalidValue {
+            name: "id".to_owned(),
+            value: "abc".to_owned(),
+            expected: "u32",
+        }
+    );
+
+    insta::assert_snapshot!(error, @"invalid value `abc` for parameter `id`, expected u32");
+
+    let search = router.search("/export.csv").unwrap();
+    let error = search.deserialize::<Format>().unwrap_err();
+    insta::assert_snapshot!(error, @"parameter `format`: unknown variant `csv`, expected `json` or `xml`");
+
+    Ok(())
+}
+
+#[test]
+fn deserialize_missing_field() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/users/<id>", 1)?;
+
+    let router = builder.build();
+
+    let search = router.search("/users/123").unwrap();
+    let error = search.deserialize::<User<'_>>().unwrap_err();
+    insta::assert_snapshot!(error, @"missing field `name`");
+
+    Ok(())
+}
@@ -0,0 +1,91 @@
+#![expect(missing_docs, reason = "Tests")]
+
+use core::error::Error;
+
+use similar_asserts::assert_eq;
+use wayfind::{Entry, RouterBuilder};
+
+mod common;
+use common::redact;
+
+#[test]
+fn duplicates_values() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new().with_duplicates();
+    builder.insert("/webhooks/<event>", "audit")?;
+    builder.insert("/webhooks/<event>", "metrics")?;
+    builder.insert("/webhooks/push", "ci")?;
+    builder.insert("/webhooks/<event>", "slack")?;
+    builder.insert("/<*path>", "fallback")?;
+    builder.insert("/<*path>", "logger")?;
+
+    let router = builder.build();
+    insta::assert_snapshot!(router, @r"
+    /
+    ├─ webhooks/
+    │  ├─ push
+    │  ╰─ <event>
+    ╰─ <*path>
+    ");
+
+    let search = router.search("/webhooks/release").unwrap();
+    assert_eq!(search.data(), &"audit");
+    assert_eq!(
+        search.values().collect::<Vec<_>>(),
+        [&"audit", &"metrics", &"slack"]
+    );
+
+    let search = router.search("/webhooks/push").unwrap();
+    assert_eq!(search.values().collect::<Vec<_>>(), [&"ci"]);
+
+    let search = router.search("/other/path").unwrap();
+    assert_eq!(
+        search.values().collect::<Vec<_>>(),
+        [&"fallback", &"logger"]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn duplicates_conflict() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new().with_duplicates();
+    builder.insert("/webhooks/<event>", 1)?;
+
+    let error = builder.insert("/webhooks/<name>", 2).unwrap_err();
+    insta::assert_snapshot!(redact(&error), @"conflicts with `/webhooks/<event>` (inserted at [location]), both match `/webhooks/x`");
+
+    let mut builder = RouterBuilder::new();
+    builder.insert("/webhooks/<event>", 1)?;
+
+    let error = builder.insert("/webhooks/<event>", 2).unwrap_err();
+    insta::assert_snapshot!(redact(&error), @"conflicts with `/webhooks/<event>` (inserted at [location]), both match `/webhooks/x`");
+
+    Ok(())
+}
+
+#[test]
+fn duplicates_upsert() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new().with_duplicates();
+    builder.insert("/webhooks/<event>", "audit")?;
+    builder.insert("/webhooks/<event>", "metrics")?;
+    builder.insert("/webhooks/push", "ci")?;
+    builder.insert("/webhooks/push", "deploy")?;
+
+    assert_eq!(builder.upsert("/webhooks/<event>", "slack")?, Some("audit"));
+
+    let Entry::Occupied(mut entry) = builder.entry("/webhooks/push")? else {
+        return Err("expected an occupied entry".into());
+    };
+
+    assert_eq!(entry.insert("release"), "ci");
+
+    let router = builder.build();
+
+    let search = router.search("/webhooks/opened").unwrap();
+    assert_eq!(search.values().collect::<Vec<_>>(), [&"slack"]);
+
+    let search = router.search("/webhooks/push").unwrap();
+    assert_eq!(search.values().collect::<Vec<_>>(), [&"release"]);
+
+    Ok(())
+}
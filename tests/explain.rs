@@ -0,0 +1,110 @@
+#![expect(missing_docs, reason = "Tests")]
+
+use core::error::Error;
+
+use similar_asserts::assert_eq;
+use wayfind::{NodeKind, RouterBuilder, Step};
+
+#[test]
+fn explain_backtrack() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/users/<id>", 1)?;
+    builder.insert("/users/<id>/files/<name>.pdf", 2)?;
+    builder.insert("/<*catch_all>", 3)?;
+
+    let router = builder.build();
+
+    let explanation = router.explain("/users/123/files/report.txt");
+    insta::assert_snapshot!(explanation, @r#"
+    `/users/123/files/report.txt` matched `/<*catch_all>`, decided by the end wildcard child at offset 1, after 1 earlier candidate(s) failed
+    "/" to offset 1
+      "users/" to offset 7
+        <id> = "123"
+          "/files/" to offset 17
+            skipped <name>, unreachable
+            backtrack
+          backtrack
+        backtrack
+      <*catch_all> = "users/123/files/report.txt"
+        matched `/<*catch_all>`
+    "#);
+
+    let explanation = router.explain("/users/123/files/report.pdf");
+    insta::assert_snapshot!(explanation, @r#"
+    `/users/123/files/report.pdf` matched `/users/<id>/files/<name>.pdf`, without trying any other candidate
+    "/" to offset 1
+      "users/" to offset 7
+        <id> = "123"
+          "/files/" to offset 17
+            <name> = "report" (before a suffix)
+              ".pdf" to offset 27
+                matched `/users/<id>/files/<name>.pdf`
+    "#);
+
+    Ok(())
+}
+
+#[test]
+fn explain_pruned() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/api/<*path>/help", 1)?;
+    builder.insert("/api/<version>/users", 2)?;
+
+    let router = builder.build();
+
+    let explanation = router.explain("/api/v1/posts");
+    insta::assert_snapshot!(explanation, @r#"
+    `/api/v1/posts` matched nothing
+    "/api/" to offset 5
+      skipped <version>, unreachable
+      skipped <*path>, unreachable
+      backtrack
+    "#);
+
+    let explanation = router.explain("/api/v1/x");
+    insta::assert_snapshot!(explanation, @r"
+    `/api/v1/x` matched nothing
+    pruned by bounds, 9 bytes remain but at least 11 are needed
+    ");
+
+    let explanation = router.explain("/api/v1/users");
+    assert_eq!(
+        explanation.template.as_deref(),
+        Some("/api/<version>/users")
+    );
+    assert_eq!(
+        explanation.steps.last(),
+        Some(&Step::Matched {
+            template: "/api/<version>/users".to_owned(),
+        })
+    );
+
+    Ok(())
+}
+
+#[test]
+fn explain_suffix() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/<*path>/edit", 1)?;
+    builder.insert("/<*path>/a/b/c/d", 2)?;
+
+    let router = builder.build();
+
+    let explanation = router.explain("/p/a/b/c/d");
+    insta::assert_snapshot!(explanation, @r#"
+    `/p/a/b/c/d` matched `/<*path>/a/b/c/d`, decided by the wildcard child at offset 1, after 1 earlier candidate(s) failed
+    "/" to offset 1
+      skipped <*path> = "p/a", no suffix follows
+      <*path> = "p" (before a suffix)
+        "/" to offset 3
+          "a/b/c/d" to offset 10
+            matched `/<*path>/a/b/c/d`
+    "#);
+
+    let decision = explanation.decision.unwrap();
+    assert_eq!(decision.kind, NodeKind::Wildcard);
+    assert_eq!(decision.offset, 1);
+    assert_eq!(decision.rejected, 1);
+
+    Ok(())
+}
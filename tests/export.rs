@@ -0,0 +1,78 @@
+#![expect(missing_docs, reason = "Tests")]
+
+use core::error::Error;
+
+use wayfind::RouterBuilder;
+
+fn router() -> Result<wayfind::Router<usize>, Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/users/<id>", 1)?;
+    builder.insert("/users/<id>/files/<name>.pdf", 2)?;
+    builder.insert("/api/<*path>/help", 3)?;
+    builder.insert("/<*catch_all>", 4)?;
+    Ok(builder.build())
+}
+
+#[test]
+fn export_dot() -> Result<(), Box<dyn Error>> {
+    let router = router()?;
+    insta::assert_snapshot!(router.to_dot(), @r#"
+    digraph router {
+        node [shape=box];
+        n0 [label="root"];
+        n1 [label="static: /"];
+        n2 [label="static: api/"];
+        n3 [label="wildcard: <*path>"];
+        n4 [label="static: /help\n/api/<*path>/help", peripheries=2];
+        n5 [label="static: users/"];
+        n6 [label="dynamic: <id>\n/users/<id>", peripheries=2];
+        n7 [label="static: /files/"];
+        n8 [label="dynamic: <name>"];
+        n9 [label="static: .pdf\n/users/<id>/files/<name>.pdf", peripheries=2];
+        n10 [label="end wildcard: <*catch_all>\n/<*catch_all>", peripheries=2];
+        n0 -> n1;
+        n1 -> n2;
+        n2 -> n3 [label="segment"];
+        n3 -> n4;
+        n1 -> n5;
+        n5 -> n6 [label="segment"];
+        n6 -> n7;
+        n7 -> n8 [label="inline"];
+        n8 -> n9;
+        n1 -> n10;
+    }
+    "#);
+
+    Ok(())
+}
+
+#[test]
+fn export_mermaid() -> Result<(), Box<dyn Error>> {
+    let router = router()?;
+    insta::assert_snapshot!(router.to_mermaid(), @r#"
+    flowchart TD
+        n0["root"]
+        n1["static: /"]
+        n2["static: api/"]
+        n3["wildcard: #lt;*path#gt;"]
+        n4(["static: /help<br/>/api/#lt;*path#gt;/help"])
+        n5["static: users/"]
+        n6(["dynamic: #lt;id#gt;<br/>/users/#lt;id#gt;"])
+        n7["static: /files/"]
+        n8["dynamic: #lt;name#gt;"]
+        n9(["static: .pdf<br/>/users/#lt;id#gt;/files/#lt;name#gt;.pdf"])
+        n10(["end wildcard: #lt;*catch_all#gt;<br/>/#lt;*catch_all#gt;"])
+        n0 --> n1
+        n1 --> n2
+        n2 -->|segment| n3
+        n3 --> n4
+        n1 --> n5
+        n5 -->|segment| n6
+        n6 --> n7
+        n7 -->|inline| n8
+        n8 --> n9
+        n1 --> n10
+    "#);
+
+    Ok(())
+}
@@ -0,0 +1,187 @@
+#![expect(missing_docs, clippy::panic_in_result_fn, reason = "Tests")]
+
+use core::error::Error;
+
+use similar_asserts::assert_eq;
+use wayfind::{Router, RouterBuilder};
+
+mod common;
+use common::redact;
+
+const TEMPLATES: &[&str] = &[
+    "/users/<id>",
+    "/",
+    "/users",
+    "/users/<id>/posts",
+    "/users/me",
+    "/users/<id>.<ext>",
+    "/u",
+    "/files/<*path>",
+    "/files/<*path>/edit",
+    "/<*catch_all>",
+    "/api/v<version>/items",
+    "/api/v<version>/items/<item>",
+    "/api/<*rest>/raw",
+    "/static/<file>.css",
+    "/static/<file>.min.js",
+    "/static/<file>",
+    "/<tenant>/dashboard",
+    "/<tenant>/settings/<key>",
+    "/<tenant>/<*rest>.json",
+    "/users/<id>/<*rest>",
+    "/posts/<year>-<month>-<day>",
+    "/posts/<slug>",
+];
+
+const PATHS: &[&str] = &[
+    "/",
+    "/u",
+    "/users",
+    "/users/me",
+    "/users/123",
+    "/users/123.json",
+    "/users/123/posts",
+    "/users/123/a/b",
+    "/files/a/b.txt",
+    "/files/a/b/edit",
+    "/api/v2/items",
+    "/api/v2/items/9",
+    "/api/a/b/raw",
+    "/static/app.css",
+    "/static/app.min.js",
+    "/static/app.js",
+    "/acme/dashboard",
+    "/acme/settings/theme",
+    "/acme/a/b.json",
+    "/posts/2024-01-02",
+    "/posts/hello-world",
+    "/unknown/path",
+];
+
+fn describe(router: &Router<usize>) -> Vec<String> {
+    // Inserts keep the needles of replaced reachability conditions, so only the heap size may differ.
+    let mut stats = router.stats();
+    stats.heap_size = 0;
+
+    let mut description = vec![router.to_string(), router.to_dot(), format!("{stats:?}")];
+
+    for path in PATHS {
+        let search = router.search(path).map(|search| {
+            format!(
+                "{} {} {:?}",
+                search.data(),
+                search.template(),
+                search.parameters()
+            )
+        });
+
+        description.push(format!("{path} => {search:?}"));
+    }
+
+    description
+}
+
+fn build(templates: &[&str]) -> Result<Router<usize>, Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    for (index, template) in templates.iter().enumerate() {
+        builder.insert(template, index)?;
+    }
+
+    Ok(builder.build())
+}
+
+#[test]
+fn incremental_matches_build() -> Result<(), Box<dyn Error>> {
+    let expected = describe(&build(TEMPLATES)?);
+
+    for split in 0..=TEMPLATES.len() {
+        let (built, inserted) = TEMPLATES.split_at(split);
+
+        let mut router = build(built)?;
+        for (index, template) in inserted.iter().enumerate() {
+            router.insert(template, split + index)?;
+        }
+
+        assert_eq!(describe(&router), expected, "split at {split}");
+        assert!(
+            router.stats().heap_size >= build(TEMPLATES)?.stats().heap_size,
+            "split at {split}"
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn incremental_matches_build_reversed() -> Result<(), Box<dyn Error>> {
+    let reversed: Vec<&str> = TEMPLATES.iter().rev().copied().collect();
+    let expected = describe(&build(&reversed)?);
+
+    let mut router = RouterBuilder::new().build();
+    for (index, template) in reversed.iter().enumerate() {
+        router.insert(template, index)?;
+    }
+
+    assert_eq!(describe(&router), expected);
+
+    Ok(())
+}
+
+#[test]
+fn incremental_errors() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/users/<id>", 1)?;
+
+    let mut router = builder.build();
+    router.insert("/users/<id>/posts", 2)?;
+
+    let error = router.insert("/users/<name>", 3).unwrap_err();
+    insta::assert_snapshot!(redact(&error), @"conflicts with `/users/<id>` (inserted at [location]), both match `/users/x`");
+
+    let error = router.insert("/users/<id>/posts", 3).unwrap_err();
+    insta::assert_snapshot!(redact(&error), @"conflicts with `/users/<id>/posts` (inserted at [location]), both match `/users/x/posts`");
+
+    let error = router.insert("/users/<id", 3).unwrap_err();
+    insta::assert_snapshot!(error, @"unbalanced angle bracket");
+
+    assert_eq!(
+        router.search("/users/123").map(|search| *search.data()),
+        Some(1)
+    );
+    assert_eq!(
+        router
+            .search("/users/123/posts")
+            .map(|search| *search.data()),
+        Some(2)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn incremental_duplicates() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new().with_duplicates();
+    builder.insert("/webhooks/<event>", "audit")?;
+
+    let mut router = builder.build();
+    router.insert("/webhooks/<event>", "metrics")?;
+    router.insert("/webhooks/push", "ci")?;
+    router.insert("/webhooks/push", "deploy")?;
+
+    let error = router.insert("/webhooks/<name>", "slack").unwrap_err();
+    insta::assert_snapshot!(redact(&error), @"conflicts with `/webhooks/<event>` (inserted at [location]), both match `/webhooks/x`");
+
+    let search = router.search("/webhooks/opened").unwrap();
+    assert_eq!(search.values().collect::<Vec<_>>(), [&"audit", &"metrics"]);
+
+    let search = router.search("/webhooks/push").unwrap();
+    assert_eq!(search.values().collect::<Vec<_>>(), [&"ci", &"deploy"]);
+
+    let mut router = RouterBuilder::new().build();
+    router.insert("/webhooks/push", "ci")?;
+
+    let error = router.insert("/webhooks/push", "deploy").unwrap_err();
+    insta::assert_snapshot!(redact(&error), @"conflicts with `/webhooks/push` (inserted at [location]), both match `/webhooks/push`");
+
+    Ok(())
+}
@@ -1,24 +1,50 @@
 #![expect(missing_docs, reason = "Tests")]
 
 use core::error::Error;
+use core::panic::Location;
 
 use similar_asserts::assert_eq;
-use wayfind::{InsertError, RouterBuilder};
+use wayfind::{ExtendError, InsertError, InsertFailure, RouterBuilder};
+
+mod common;
+use common::redact;
+
+/// Inserts a template, returning the call site recorded for it.
+#[track_caller]
+fn insert<T>(
+    builder: &mut RouterBuilder<T>,
+    template: &str,
+    data: T,
+) -> Result<&'static Location<'static>, InsertError> {
+    builder.insert(template, data)?;
+    Ok(Location::caller())
+}
+
+/// Extends a builder, returning the call site recorded for each inserted template.
+#[track_caller]
+fn try_extend<'a, T, I: IntoIterator<Item = (&'a str, T)>>(
+    builder: &mut RouterBuilder<T>,
+    routes: I,
+) -> (Result<(), ExtendError>, &'static Location<'static>) {
+    (builder.try_extend(routes), Location::caller())
+}
 
 #[test]
 fn insert_conflict_static() -> Result<(), Box<dyn Error>> {
     let mut builder = RouterBuilder::new();
-    builder.insert("/test", 1)?;
+    let inserted = insert(&mut builder, "/test", 1)?;
 
     let error = builder.insert("/test", 2).unwrap_err();
     assert_eq!(
         error,
         InsertError::Conflict {
             existing: "/test".to_owned(),
+            witness: "/test".to_owned(),
+            location: inserted,
         }
     );
 
-    insta::assert_snapshot!(error, @"conflicts with `/test`");
+    insta::assert_snapshot!(redact(&error), @"conflicts with `/test` (inserted at [location]), both match `/test`");
 
     let router = builder.build();
     insta::assert_snapshot!(router, @"/test");
@@ -29,17 +55,19 @@ fn insert_conflict_static() -> Result<(), Box<dyn Error>> {
 #[test]
 fn insert_conflict_dynamic() -> Result<(), Box<dyn Error>> {
     let mut builder = RouterBuilder::new();
-    builder.insert("/<id>", 1)?;
+    let inserted = insert(&mut builder, "/<id>", 1)?;
 
     let error = builder.insert("/<id>", 2).unwrap_err();
     assert_eq!(
         error,
         InsertError::Conflict {
             existing: "/<id>".to_owned(),
+            witness: "/x".to_owned(),
+            location: inserted,
         }
     );
 
-    insta::assert_snapshot!(error, @"conflicts with `/<id>`");
+    insta::assert_snapshot!(redact(&error), @"conflicts with `/<id>` (inserted at [location]), both match `/x`");
 
     let router = builder.build();
     insta::assert_snapshot!(router, @r"
@@ -53,17 +81,19 @@ fn insert_conflict_dynamic() -> Result<(), Box<dyn Error>> {
 #[test]
 fn insert_conflict_dynamic_structural() -> Result<(), Box<dyn Error>> {
     let mut builder = RouterBuilder::new();
-    builder.insert("/<id>", 1)?;
+    let inserted = insert(&mut builder, "/<id>", 1)?;
 
     let error = builder.insert("/<user>", 2).unwrap_err();
     assert_eq!(
         error,
         InsertError::Conflict {
             existing: "/<id>".to_owned(),
+            witness: "/x".to_owned(),
+            location: inserted,
         }
     );
 
-    insta::assert_snapshot!(error, @"conflicts with `/<id>`");
+    insta::assert_snapshot!(redact(&error), @"conflicts with `/<id>` (inserted at [location]), both match `/x`");
 
     let router = builder.build();
     insta::assert_snapshot!(router, @r"
@@ -77,17 +107,19 @@ fn insert_conflict_dynamic_structural() -> Result<(), Box<dyn Error>> {
 #[test]
 fn insert_conflict_wildcard() -> Result<(), Box<dyn Error>> {
     let mut builder = RouterBuilder::new();
-    builder.insert("/<*path>/edit", 1)?;
+    let inserted = insert(&mut builder, "/<*path>/edit", 1)?;
 
     let error = builder.insert("/<*path>/edit", 2).unwrap_err();
     assert_eq!(
         error,
         InsertError::Conflict {
             existing: "/<*path>/edit".to_owned(),
+            witness: "/x/y/edit".to_owned(),
+            location: inserted,
         }
     );
 
-    insta::assert_snapshot!(error, @"conflicts with `/<*path>/edit`");
+    insta::assert_snapshot!(redact(&error), @"conflicts with `/<*path>/edit` (inserted at [location]), both match `/x/y/edit`");
 
     let router = builder.build();
     insta::assert_snapshot!(router, @r"
@@ -102,17 +134,19 @@ fn insert_conflict_wildcard() -> Result<(), Box<dyn Error>> {
 #[test]
 fn insert_conflict_wildcard_structural() -> Result<(), Box<dyn Error>> {
     let mut builder = RouterBuilder::new();
-    builder.insert("/<*path>/edit", 1)?;
+    let inserted = insert(&mut builder, "/<*path>/edit", 1)?;
 
     let error = builder.insert("/<*slug>/edit", 2).unwrap_err();
     assert_eq!(
         error,
         InsertError::Conflict {
             existing: "/<*path>/edit".to_owned(),
+            witness: "/x/y/edit".to_owned(),
+            location: inserted,
         }
     );
 
-    insta::assert_snapshot!(error, @"conflicts with `/<*path>/edit`");
+    insta::assert_snapshot!(redact(&error), @"conflicts with `/<*path>/edit` (inserted at [location]), both match `/x/y/edit`");
 
     let router = builder.build();
     insta::assert_snapshot!(router, @r"
@@ -127,17 +161,19 @@ fn insert_conflict_wildcard_structural() -> Result<(), Box<dyn Error>> {
 #[test]
 fn insert_conflict_end_wildcard() -> Result<(), Box<dyn Error>> {
     let mut builder = RouterBuilder::new();
-    builder.insert("/<*catch_all>", 1)?;
+    let inserted = insert(&mut builder, "/<*catch_all>", 1)?;
 
     let error = builder.insert("/<*catch_all>", 2).unwrap_err();
     assert_eq!(
         error,
         InsertError::Conflict {
             existing: "/<*catch_all>".to_owned(),
+            witness: "/x/y".to_owned(),
+            location: inserted,
         }
     );
 
-    insta::assert_snapshot!(error, @"conflicts with `/<*catch_all>`");
+    insta::assert_snapshot!(redact(&error), @"conflicts with `/<*catch_all>` (inserted at [location]), both match `/x/y`");
 
     let router = builder.build();
     insta::assert_snapshot!(router, @r"
@@ -151,17 +187,19 @@ fn insert_conflict_end_wildcard() -> Result<(), Box<dyn Error>> {
 #[test]
 fn insert_conflict_end_wildcard_structural() -> Result<(), Box<dyn Error>> {
     let mut builder = RouterBuilder::new();
-    builder.insert("/<*catch_all>", 1)?;
+    let inserted = insert(&mut builder, "/<*catch_all>", 1)?;
 
     let error = builder.insert("/<*files>", 2).unwrap_err();
     assert_eq!(
         error,
         InsertError::Conflict {
             existing: "/<*catch_all>".to_owned(),
+            witness: "/x/y".to_owned(),
+            location: inserted,
         }
     );
 
-    insta::assert_snapshot!(error, @"conflicts with `/<*catch_all>`");
+    insta::assert_snapshot!(redact(&error), @"conflicts with `/<*catch_all>` (inserted at [location]), both match `/x/y`");
 
     let router = builder.build();
     insta::assert_snapshot!(router, @r"
@@ -180,11 +218,112 @@ fn insert_duplicate_parameter() {
         error,
         InsertError::DuplicateParameter {
             name: "id".to_owned(),
+            template: "/<*id>/users/<id>".to_owned(),
+            span: 13..17,
         }
     );
 
     insta::assert_snapshot!(error, @"duplicate parameter name `id`");
+    insta::assert_snapshot!(format!("{error:#}"), @r"
+    duplicate parameter name `id`
+    /<*id>/users/<id>
+                 ^^^^
+    ");
 
     let router = builder.build();
     insta::assert_snapshot!(router, @"");
 }
+
+#[test]
+fn insert_try_extend() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    let inserted = insert(&mut builder, "/users", 0)?;
+
+    let (result, extended) = try_extend(
+        &mut builder,
+        [
+            ("/users/<id>", 1),
+            ("/users", 2),
+            ("users", 3),
+            ("/posts/<id>", 4),
+            ("/users/<user>", 5),
+        ],
+    );
+
+    let error = result.unwrap_err();
+
+    assert_eq!(
+        error,
+        ExtendError {
+            failures: vec![
+                InsertFailure {
+                    index: 1,
+                    template: "/users".to_owned(),
+                    error: InsertError::Conflict {
+                        existing: "/users".to_owned(),
+                        witness: "/users".to_owned(),
+                        location: inserted,
+                    },
+                },
+                InsertFailure {
+                    index: 2,
+                    template: "users".to_owned(),
+                    error: InsertError::MissingSlash {
+                        template: "users".to_owned(),
+                        span: 0..1,
+                    },
+                },
+                InsertFailure {
+                    index: 4,
+                    template: "/users/<user>".to_owned(),
+                    error: InsertError::Conflict {
+                        existing: "/users/<id>".to_owned(),
+                        witness: "/users/x".to_owned(),
+                        location: extended,
+                    },
+                },
+            ],
+        }
+    );
+
+    insta::assert_snapshot!(redact(&error), @r"
+    failed to insert 3 template(s)
+    - #1 `/users`: conflicts with `/users` (inserted at [location]), both match `/users`
+    - #2 `users`: missing leading slash
+    - #4 `/users/<user>`: conflicts with `/users/<id>` (inserted at [location]), both match `/users/x`
+    ");
+
+    let router = builder.build();
+    insta::assert_snapshot!(router, @r"
+    /
+    ├─ posts/
+    │  ╰─ <id>
+    ╰─ users
+       ╰─ /
+          ╰─ <id>
+    ");
+
+    let mut builder = RouterBuilder::new();
+    builder.try_extend([("/users", 1), ("/posts", 2)])?;
+
+    Ok(())
+}
+
+#[test]
+fn insert_from_iter() {
+    let mut builder: RouterBuilder<_> = [("/users", 1), ("/users", 2), ("/<id>", 3)]
+        .into_iter()
+        .collect();
+
+    builder.extend([("/posts", 4), ("posts", 5)]);
+
+    let router = builder.build();
+    insta::assert_snapshot!(router, @r"
+    /
+    ├─ posts
+    ├─ users
+    ╰─ <id>
+    ");
+
+    assert_eq!(router.search("/users").unwrap().data(), &1);
+}
@@ -0,0 +1,172 @@
+#![expect(missing_docs, reason = "Tests")]
+
+use core::error::Error;
+
+use similar_asserts::assert_eq;
+use wayfind::{Lint, LintConfig, LintRule, RouterBuilder};
+
+#[path = "../benches/fixtures/gitlab_routes.rs"]
+mod gitlab_routes;
+
+fn lints(builder: &RouterBuilder<usize>, config: LintConfig) -> String {
+    builder
+        .lint(config)
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn lint_trailing_slash() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/", 1)?;
+    builder.insert("/users", 2)?;
+    builder.insert("/users/", 3)?;
+    builder.insert("/posts/", 4)?;
+
+    let lints = builder.lint(LintConfig::none().with(LintRule::TrailingSlash));
+    assert_eq!(
+        lints,
+        vec![Lint {
+            rule: LintRule::TrailingSlash,
+            templates: vec!["/users".to_owned(), "/users/".to_owned()],
+            message: "`/users` and `/users/` differ only by a trailing slash".to_owned(),
+        }]
+    );
+
+    insta::assert_snapshot!(lints[0], @"[trailing-slash] `/users` and `/users/` differ only by a trailing slash");
+
+    Ok(())
+}
+
+#[test]
+fn lint_snake_case() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/users/<user_id>", 1)?;
+    builder.insert("/posts/<postId>", 2)?;
+    builder.insert("/files/<*File-Path>", 3)?;
+    builder.insert("/tags/<_tag2>", 4)?;
+
+    insta::assert_snapshot!(lints(&builder, LintConfig::none().with(LintRule::SnakeCase)), @r"
+    [snake-case] parameter `postId` in `/posts/<postId>` is not snake_case
+    [snake-case] parameter `File-Path` in `/files/<*File-Path>` is not snake_case
+    ");
+
+    Ok(())
+}
+
+#[test]
+fn lint_inconsistent_name() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/users/<id>", 1)?;
+    builder.insert("/users/<id>/edit", 2)?;
+    builder.insert("/users/<user>/posts/<id>", 3)?;
+    builder.insert("/posts/<id>", 4)?;
+
+    insta::assert_snapshot!(lints(&builder, LintConfig::none().with(LintRule::InconsistentName)), @r"
+    [inconsistent-name] sibling parameters after `/users/` have different names: `id` in `/users/<id>`, `user` in `/users/<user>/posts/<id>`
+    ");
+
+    Ok(())
+}
+
+#[test]
+fn lint_inconsistent_name_gitlab() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    for (index, route) in gitlab_routes::routes().iter().enumerate() {
+        builder.insert(route, index)?;
+    }
+
+    let lints = builder.lint(LintConfig::none().with(LintRule::InconsistentName));
+    insta::assert_snapshot!(lints.iter().map(ToString::to_string).collect::<Vec<_>>().join("\n"), @r"
+    [inconsistent-name] sibling parameters after `/` have different names: `id` in `/<*id>.<format>`, `namespace_id` in `/<*namespace_id>/<id>/activity`, `repository_path` in `/<*repository_path>/git-receive-pack`
+    [inconsistent-name] sibling parameters after `/-/remote_development/workspaces/` have different names: `id` in `/-/remote_development/workspaces/<id>`, `workspace_id` in `/-/remote_development/workspaces/<workspace_id>/workspaces`
+    [inconsistent-name] sibling parameters after `/-/remote_development/workspaces/<*vueroute>/` have different names: `id` in `/-/remote_development/workspaces/<*vueroute>/<id>`, `workspace_id` in `/-/remote_development/workspaces/<*vueroute>/<workspace_id>/workspaces`
+    [inconsistent-name] sibling parameters after `/-/snippets/` have different names: `id` in `/-/snippets/<id>`, `snippet_id` in `/-/snippets/<snippet_id>/notes`
+    [inconsistent-name] sibling parameters after `/<*namespace_id>/` have different names: `id` in `/<*namespace_id>/<id>/activity`, `project_id` in `/<*namespace_id>/<project_id>`
+    [inconsistent-name] sibling parameters after `/<*namespace_id>/<project_id>/-/analytics/value_stream_analytics/value_streams/` have different names: `id` in `/<*namespace_id>/<project_id>/-/analytics/value_stream_analytics/value_streams/<id>`, `value_stream_id` in `/<*namespace_id>/<project_id>/-/analytics/value_stream_analytics/value_streams/<value_stream_id>/stages`
+    [inconsistent-name] sibling parameters after `/<*namespace_id>/<project_id>/-/cadences/` have different names: `id` in `/<*namespace_id>/<project_id>/-/cadences/<id>`, `iteration_cadence_id` in `/<*namespace_id>/<project_id>/-/cadences/<iteration_cadence_id>/iterations`
+    [inconsistent-name] sibling parameters after `/<*namespace_id>/<project_id>/-/cadences/<*vueroute>/` have different names: `id` in `/<*namespace_id>/<project_id>/-/cadences/<*vueroute>/<id>`, `iteration_cadence_id` in `/<*namespace_id>/<project_id>/-/cadences/<*vueroute>/<iteration_cadence_id>/iterations`
+    [inconsistent-name] sibling parameters after `/<*namespace_id>/<project_id>/-/clusters/` have different names: `cluster_id` in `/<*namespace_id>/<project_id>/-/clusters/<cluster_id>/integration/create_or_update`, `id` in `/<*namespace_id>/<project_id>/-/clusters/<id>`
+    [inconsistent-name] sibling parameters after `/<*namespace_id>/<project_id>/-/environments/` have different names: `environment_id` in `/<*namespace_id>/<project_id>/-/environments/<environment_id>/deployments`, `id` in `/<*namespace_id>/<project_id>/-/environments/<id>`
+    [inconsistent-name] sibling parameters after `/<*namespace_id>/<project_id>/-/feature_flags/` have different names: `feature_flag_iid` in `/<*namespace_id>/<project_id>/-/feature_flags/<feature_flag_iid>/issues`, `iid` in `/<*namespace_id>/<project_id>/-/feature_flags/<iid>`
+    [inconsistent-name] sibling parameters after `/<*namespace_id>/<project_id>/-/harbor/repositories/` have different names: `id` in `/<*namespace_id>/<project_id>/-/harbor/repositories/<id>`, `repository_id` in `/<*namespace_id>/<project_id>/-/harbor/repositories/<repository_id>/artifacts`
+    [inconsistent-name] sibling parameters after `/<*namespace_id>/<project_id>/-/hooks/` have different names: `hook_id` in `/<*namespace_id>/<project_id>/-/hooks/<hook_id>/hook_logs/<id>`, `id` in `/<*namespace_id>/<project_id>/-/hooks/<id>`
+    [inconsistent-name] sibling parameters after `/<*namespace_id>/<project_id>/-/issues/` have different names: `id` in `/<*namespace_id>/<project_id>/-/issues/<id>`, `issue_id` in `/<*namespace_id>/<project_id>/-/issues/<issue_id>/feature_flags`
+    [inconsistent-name] sibling parameters after `/<*namespace_id>/<project_id>/-/jobs/` have different names: `id` in `/<*namespace_id>/<project_id>/-/jobs/<id>`, `job_id` in `/<*namespace_id>/<project_id>/-/jobs/<job_id>/artifacts/browse`
+    [inconsistent-name] sibling parameters after `/<*namespace_id>/<project_id>/-/merge_requests/` have different names: `id` in `/<*namespace_id>/<project_id>/-/merge_requests/<id>`, `merge_request_id` in `/<*namespace_id>/<project_id>/-/merge_requests/<merge_request_id>/approver_groups/<id>`
+    [inconsistent-name] sibling parameters after `/<*namespace_id>/<project_id>/-/ml/models/` have different names: `model_id` in `/<*namespace_id>/<project_id>/-/ml/models/<model_id>`, `model_model_id` in `/<*namespace_id>/<project_id>/-/ml/models/<model_model_id>/versions/<model_version_id>`
+    [inconsistent-name] sibling parameters after `/<*namespace_id>/<project_id>/-/pipelines/` have different names: `id` in `/<*namespace_id>/<project_id>/-/pipelines/<id>`, `pipeline_id` in `/<*namespace_id>/<project_id>/-/pipelines/<pipeline_id>/stages/<stage_name>/play_manual`
+    [inconsistent-name] sibling parameters after `/<*namespace_id>/<project_id>/-/security/vulnerabilities/` have different names: `id` in `/<*namespace_id>/<project_id>/-/security/vulnerabilities/<id>`, `vulnerability_id` in `/<*namespace_id>/<project_id>/-/security/vulnerabilities/<vulnerability_id>/notes`
+    [inconsistent-name] sibling parameters after `/<*namespace_id>/<project_id>/-/settings/integrations/` have different names: `id` in `/<*namespace_id>/<project_id>/-/settings/integrations/<id>`, `integration_id` in `/<*namespace_id>/<project_id>/-/settings/integrations/<integration_id>/hook_logs/<id>`
+    [inconsistent-name] sibling parameters after `/<*namespace_id>/<project_id>/-/snippets/` have different names: `id` in `/<*namespace_id>/<project_id>/-/snippets/<id>`, `snippet_id` in `/<*namespace_id>/<project_id>/-/snippets/<snippet_id>/raw/<ref>/<*path>/`
+    [inconsistent-name] sibling parameters after `/<*namespace_id>/<project_id>/builds/` have different names: `build_id` in `/<*namespace_id>/<project_id>/builds/<build_id>/artifacts/browse`, `id` in `/<*namespace_id>/<project_id>/builds/<id>`
+    [inconsistent-name] sibling parameters after `/admin/background_migrations/` have different names: `background_migration_id` in `/admin/background_migrations/<background_migration_id>/batched_jobs/<id>`, `id` in `/admin/background_migrations/<id>`
+    [inconsistent-name] sibling parameters after `/admin/clusters/` have different names: `cluster_id` in `/admin/clusters/<cluster_id>/integration/create_or_update`, `id` in `/admin/clusters/<id>`
+    [inconsistent-name] sibling parameters after `/admin/credentials/` have different names: `credential_id` in `/admin/credentials/<credential_id>/resources/<resource_id>/revoke`, `id` in `/admin/credentials/<id>`
+    [inconsistent-name] sibling parameters after `/admin/hooks/` have different names: `hook_id` in `/admin/hooks/<hook_id>/hook_logs/<id>`, `id` in `/admin/hooks/<id>`
+    [inconsistent-name] sibling parameters after `/admin/projects/<*namespace_id>/` have different names: `id` in `/admin/projects/<*namespace_id>/<id>`, `project_id` in `/admin/projects/<*namespace_id>/<project_id>/runner_projects`
+    [inconsistent-name] sibling parameters after `/admin/topics/` have different names: `id` in `/admin/topics/<id>`, `topic_id` in `/admin/topics/<topic_id>/avatar`
+    [inconsistent-name] sibling parameters after `/admin/users/` have different names: `id` in `/admin/users/<id>`, `user_id` in `/admin/users/<user_id>/identities`
+    [inconsistent-name] sibling parameters after `/groups/` have different names: `group_id` in `/groups/<*group_id>/-/achievements`, `id` in `/groups/<*id>.<format>`
+    [inconsistent-name] sibling parameters after `/groups/<*group_id>/-/analytics/value_stream_analytics/value_streams/` have different names: `id` in `/groups/<*group_id>/-/analytics/value_stream_analytics/value_streams/<id>`, `value_stream_id` in `/groups/<*group_id>/-/analytics/value_stream_analytics/value_streams/<value_stream_id>/stages`
+    [inconsistent-name] sibling parameters after `/groups/<*group_id>/-/cadences/` have different names: `id` in `/groups/<*group_id>/-/cadences/<id>`, `iteration_cadence_id` in `/groups/<*group_id>/-/cadences/<iteration_cadence_id>/iterations`
+    [inconsistent-name] sibling parameters after `/groups/<*group_id>/-/cadences/<*vueroute>/` have different names: `id` in `/groups/<*group_id>/-/cadences/<*vueroute>/<id>`, `iteration_cadence_id` in `/groups/<*group_id>/-/cadences/<*vueroute>/<iteration_cadence_id>/iterations`
+    [inconsistent-name] sibling parameters after `/groups/<*group_id>/-/clusters/` have different names: `cluster_id` in `/groups/<*group_id>/-/clusters/<cluster_id>/integration/create_or_update`, `id` in `/groups/<*group_id>/-/clusters/<id>`
+    [inconsistent-name] sibling parameters after `/groups/<*group_id>/-/epics/` have different names: `epic_id` in `/groups/<*group_id>/-/epics/<epic_id>/issues`, `id` in `/groups/<*group_id>/-/epics/<id>`
+    [inconsistent-name] sibling parameters after `/groups/<*group_id>/-/harbor/repositories/` have different names: `id` in `/groups/<*group_id>/-/harbor/repositories/<id>`, `repository_id` in `/groups/<*group_id>/-/harbor/repositories/<repository_id>/artifacts`
+    [inconsistent-name] sibling parameters after `/groups/<*group_id>/-/hooks/` have different names: `hook_id` in `/groups/<*group_id>/-/hooks/<hook_id>/hook_logs/<id>`, `id` in `/groups/<*group_id>/-/hooks/<id>`
+    ");
+
+    Ok(())
+}
+
+#[test]
+fn lint_inline_wildcard() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/files/<*path>/edit", 1)?;
+    builder.insert("/files/<*path>.pdf", 2)?;
+    builder.insert("/static/<*path>", 3)?;
+
+    insta::assert_snapshot!(lints(&builder, LintConfig::none().with(LintRule::InlineWildcard)), @r"
+    [inline-wildcard] wildcard `<*path>` in `/files/<*path>.pdf` is not followed by `/`, requiring an inline search
+    ");
+
+    Ok(())
+}
+
+#[test]
+fn lint_too_many_parameters() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/<a>/<b>/<c>/<d>", 1)?;
+    builder.insert("/<a>/<b>/<c>/<d>/<e>", 2)?;
+
+    insta::assert_snapshot!(lints(&builder, LintConfig::none().with(LintRule::TooManyParameters)), @r"
+    [too-many-parameters] `/<a>/<b>/<c>/<d>/<e>` has 5 parameters, more than the 4 stored without allocating
+    ");
+
+    Ok(())
+}
+
+#[test]
+fn lint_config() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/users/<userId>", 1)?;
+    builder.insert("/users/<userId>/", 2)?;
+    builder.insert("/files/<*path>.pdf", 3)?;
+
+    insta::assert_snapshot!(lints(&builder, LintConfig::new()), @r"
+    [trailing-slash] `/users/<userId>` and `/users/<userId>/` differ only by a trailing slash
+    [snake-case] parameter `userId` in `/users/<userId>` is not snake_case
+    [snake-case] parameter `userId` in `/users/<userId>/` is not snake_case
+    [inline-wildcard] wildcard `<*path>` in `/files/<*path>.pdf` is not followed by `/`, requiring an inline search
+    ");
+    insta::assert_snapshot!(lints(&builder, LintConfig::default().without(LintRule::SnakeCase)), @r"
+    [trailing-slash] `/users/<userId>` and `/users/<userId>/` differ only by a trailing slash
+    [inline-wildcard] wildcard `<*path>` in `/files/<*path>.pdf` is not followed by `/`, requiring an inline search
+    ");
+    insta::assert_snapshot!(lints(&builder, LintConfig::none()), @"");
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+#![expect(missing_docs, clippy::panic_in_result_fn, reason = "Tests")]
+
+use core::error::Error;
+
+use similar_asserts::assert_eq;
+use wayfind::RouterBuilder;
+
+#[test]
+fn parameters_lookup() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/users/<id>/files/<*path>", 1)?;
+
+    let router = builder.build();
+
+    let search = router.search("/users/123/files/docs/report.pdf").unwrap();
+    assert_eq!(search.get("id"), Some("123"));
+    assert_eq!(search.get("path"), Some("docs/report.pdf"));
+    assert_eq!(search.get("missing"), None);
+
+    assert_eq!(search.get_index(0), Some(("id", "123")));
+    assert_eq!(search.get_index(1), Some(("path", "docs/report.pdf")));
+    assert_eq!(search.get_index(2), None);
+
+    let params = search.params();
+    assert_eq!(params.len(), 2);
+    assert!(!params.is_empty());
+    assert!(params.contains("id"));
+    assert!(!params.contains("missing"));
+    assert_eq!(&params["path"], "docs/report.pdf");
+    assert_eq!(
+        params.iter().collect::<Vec<_>>(),
+        [("id", "123"), ("path", "docs/report.pdf")]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn parameters_empty() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/users", 1)?;
+
+    let router = builder.build();
+
+    let search = router.search("/users").unwrap();
+    let params = search.params();
+    assert!(params.is_empty());
+    assert_eq!(params.get_index(0), None);
+    assert_eq!(params.into_iter().count(), 0);
+
+    Ok(())
+}
+
+#[test]
+#[should_panic(expected = "no parameter named `missing`")]
+fn parameters_index_missing() {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/users/<id>", 1).unwrap();
+
+    let router = builder.build();
+
+    let search = router.search("/users/123").unwrap();
+    let _value = &search.params()["missing"];
+}
@@ -0,0 +1,83 @@
+#![expect(missing_docs, reason = "Tests")]
+
+use core::error::Error;
+
+use similar_asserts::assert_eq;
+use wayfind::{Match, RouterBuilder, SearchScratch};
+
+#[test]
+fn scratch_reuse() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/<a>/<b>/<c>/<d>/<e>/<f>/<g>/<h>/<i>/<j>.txt", 1)?;
+    builder.insert("/<*path>/<name>.<ext>", 2)?;
+    builder.insert("/users/<id>", 3)?;
+
+    let router = builder.build();
+    let mut scratch = SearchScratch::new();
+
+    let paths = [
+        "/1/2/3/4/5/6/7/8/9/10.txt",
+        "/1/2/3/4/5/6/7/8/9/10.pdf",
+        "/a/b/c/d/e/f/g/h/i/j/k/l/m/n/o/p/q/r.tar.gz",
+        "/users/123",
+        "/users/123.json",
+        "/not/found",
+    ];
+
+    for _ in 0..2 {
+        for path in paths {
+            let expected = router.search(path);
+            let search = router.search_with(&mut scratch, path);
+
+            assert_eq!(
+                search.as_ref().map(Match::data),
+                expected.as_ref().map(Match::data),
+            );
+
+            assert_eq!(
+                search.as_ref().map(Match::parameters),
+                expected.as_ref().map(Match::parameters),
+            );
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn scratch_reuse_parameters() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/<a>/<b>/<c>/<d>/<e>/<f>", 1)?;
+    builder.insert("/users/<id>", 2)?;
+
+    let router = builder.build();
+    let mut scratch = SearchScratch::new();
+    let mut parameters = Vec::new();
+
+    let route = router.search_into_with(&mut scratch, &mut parameters, "/1/2/3/4/5/6");
+    assert_eq!(route.map(|route| *route.data()), Some(1));
+
+    let buffer = parameters.as_ptr();
+    let capacity = parameters.capacity();
+
+    for path in ["/a/b/c/d/e/f", "/users/123", "/not/found", "/6/5/4/3/2/1"] {
+        parameters.clear();
+
+        let expected = router.search(path);
+        let route = router.search_into_with(&mut scratch, &mut parameters, path);
+
+        assert_eq!(
+            route.map(|route| *route.data()),
+            expected.as_ref().map(|search| *search.data())
+        );
+        assert_eq!(
+            parameters.as_slice(),
+            expected.as_ref().map_or(&[][..], Match::parameters)
+        );
+    }
+
+    assert_eq!(parameters.as_ptr(), buffer);
+    assert_eq!(parameters.capacity(), capacity);
+
+    Ok(())
+}
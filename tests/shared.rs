@@ -0,0 +1,103 @@
+#![expect(missing_docs, reason = "Tests")]
+
+use core::error::Error;
+use std::thread;
+
+use similar_asserts::assert_eq;
+use wayfind::{Router, RouterBuilder, SharedRouter};
+
+fn version(version: usize) -> Result<Router<usize>, Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/version", version)?;
+    builder.insert(&format!("/v{version}/<id>"), version)?;
+    Ok(builder.build())
+}
+
+#[test]
+fn shared_snapshots() -> Result<(), Box<dyn Error>> {
+    let shared = SharedRouter::new(version(1)?);
+
+    let snapshot = shared.load();
+    let search = snapshot.search("/v1/123").unwrap();
+
+    let previous = shared.swap(version(2)?);
+    assert_eq!(
+        previous.search("/version").map(|search| *search.data()),
+        Some(1)
+    );
+
+    // Earlier snapshots and matches remain valid.
+    assert_eq!(search.data(), &1);
+    assert_eq!(search.parameters(), &[("id", "123")]);
+
+    let current = shared.load();
+    assert_eq!(
+        current.search("/version").map(|search| *search.data()),
+        Some(2)
+    );
+    assert_eq!(current.search("/v1/123").map(|search| *search.data()), None);
+
+    let arc = current.into_arc();
+    shared.store(version(3)?);
+    assert_eq!(arc.search("/version").map(|search| *search.data()), Some(2));
+    assert_eq!(
+        shared
+            .load()
+            .search("/version")
+            .map(|search| *search.data()),
+        Some(3)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn shared_threads() -> Result<(), Box<dyn Error>> {
+    let shared = SharedRouter::from(version(0)?);
+
+    thread::scope(|scope| -> Result<(), Box<dyn Error>> {
+        let readers: Vec<_> = (0..4)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut last = 0;
+                    for _ in 0..1_000 {
+                        let snapshot = shared.load();
+                        let current = snapshot
+                            .search("/version")
+                            .map_or(0, |search| *search.data());
+
+                        // Versions only move forwards, and always match themselves.
+                        assert_eq!(current.max(last), current);
+                        let path = format!("/v{current}/x");
+                        assert_eq!(
+                            snapshot.search(&path).map(|search| *search.data()),
+                            Some(current)
+                        );
+
+                        last = current;
+                    }
+                })
+            })
+            .collect();
+
+        for next in 1..=50 {
+            shared.store(version(next)?);
+        }
+
+        for reader in readers {
+            reader.join().map_err(|_panic| "reader panicked")?;
+        }
+
+        Ok(())
+    })?;
+
+    assert_eq!(
+        shared
+            .load()
+            .search("/version")
+            .map(|search| *search.data()),
+        Some(50)
+    );
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+#![expect(missing_docs, clippy::panic_in_result_fn, reason = "Tests")]
+
+use core::error::Error;
+
+use similar_asserts::assert_eq;
+use wayfind::{ParameterSink, RouterBuilder};
+
+/// Captures into a fixed array, ignoring any overflow.
+#[derive(Default)]
+struct Fixed<'p> {
+    values: [&'p str; 2],
+    length: usize,
+}
+
+impl<'r, 'p> ParameterSink<'r, 'p> for Fixed<'p> {
+    fn push(&mut self, _: &'r str, value: &'p str) {
+        if let Some(slot) = self.values.get_mut(self.length) {
+            *slot = value;
+        }
+
+        self.length += 1;
+    }
+
+    fn pop(&mut self) {
+        self.length -= 1;
+    }
+}
+
+#[test]
+fn sink_vec() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/<*path>/<name>.<ext>", 1)?;
+    builder.insert("/<*path>/<file>", 2)?;
+
+    let router = builder.build();
+
+    let mut parameters = vec![];
+    let route = router
+        .search_into(&mut parameters, "/a/b/c.tar.gz")
+        .unwrap();
+    assert_eq!(route.data(), &1);
+    assert_eq!(
+        parameters,
+        [("path", "a/b"), ("name", "c.tar"), ("ext", "gz")]
+    );
+
+    let mut parameters = vec![];
+    let route = router.search_into(&mut parameters, "/a/b/c").unwrap();
+    assert_eq!(route.data(), &2);
+    assert_eq!(parameters, [("path", "a/b"), ("file", "c")]);
+
+    Ok(())
+}
+
+#[test]
+fn sink_fixed() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/users/<user>/posts/<post>", 1)?;
+    builder.insert("/users/<user>", 2)?;
+
+    let router = builder.build();
+
+    let mut parameters = Fixed::default();
+    let route = router.search_into(&mut parameters, "/users/123").unwrap();
+    assert_eq!(route.data(), &2);
+    assert_eq!(parameters.length, 1);
+    assert_eq!(parameters.values[0], "123");
+
+    let mut parameters = Fixed::default();
+    let route = router
+        .search_into(&mut parameters, "/users/123/posts/456")
+        .unwrap();
+    assert_eq!(route.data(), &1);
+    assert_eq!(parameters.length, 2);
+    assert_eq!(parameters.values, ["123", "456"]);
+
+    Ok(())
+}
+
+#[test]
+fn sink_discard() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/users/<id>", 1)?;
+
+    let router = builder.build();
+
+    let route = router.search_into(&mut (), "/users/123").unwrap();
+    assert_eq!(route.template(), "/users/<id>");
+    assert_eq!(route.canonical_template(), "/users/<id>");
+
+    assert!(router.search_into(&mut (), "/posts/123").is_none());
+
+    Ok(())
+}
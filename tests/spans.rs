@@ -0,0 +1,68 @@
+#![expect(missing_docs, reason = "Tests")]
+
+use core::error::Error;
+
+use similar_asserts::assert_eq;
+use wayfind::RouterBuilder;
+
+#[test]
+fn spans_dynamic() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/users/<id>/files/<name>.<ext>", 1)?;
+
+    let router = builder.build();
+
+    let search = router.search("/users/123/files/my.report.pdf").unwrap();
+    assert_eq!(search.path(), "/users/123/files/my.report.pdf");
+    assert_eq!(
+        search.parameter_spans().collect::<Vec<_>>(),
+        [("id", 7..10), ("name", 17..26), ("ext", 27..30)]
+    );
+    assert_eq!(
+        search.static_spans().collect::<Vec<_>>(),
+        [0..7, 10..17, 26..27]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn spans_wildcard() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/<*path>/edit", 1)?;
+    builder.insert("/<*catch_all>", 2)?;
+
+    let router = builder.build();
+
+    let search = router.search("/a/b/c/edit").unwrap();
+    assert_eq!(
+        search.parameter_spans().collect::<Vec<_>>(),
+        [("path", 1..6)]
+    );
+    assert_eq!(search.static_spans().collect::<Vec<_>>(), [0..1, 6..11]);
+
+    let search = router.search("/a/b/c").unwrap();
+    assert_eq!(
+        search.parameter_spans().collect::<Vec<_>>(),
+        [("catch_all", 1..6)]
+    );
+    assert_eq!(search.static_spans().next(), Some(0..1));
+    assert_eq!(search.static_spans().count(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn spans_static() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/users", 1)?;
+
+    let router = builder.build();
+
+    let search = router.search("/users").unwrap();
+    assert_eq!(search.parameter_spans().count(), 0);
+    assert_eq!(search.static_spans().next(), Some(0..6));
+    assert_eq!(search.static_spans().count(), 1);
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+#![expect(missing_docs, clippy::panic_in_result_fn, reason = "Tests")]
+
+use core::error::Error;
+
+use similar_asserts::assert_eq;
+use wayfind::RouterBuilder;
+
+#[test]
+fn stats_counts() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/users/<id>", 1)?;
+    builder.insert("/users/<id>/files/<name>.pdf", 2)?;
+    builder.insert("/api/<*path>/help", 3)?;
+    builder.insert("/api/<*path>/docs/<page>", 4)?;
+    builder.insert("/<*catch_all>", 5)?;
+    builder.alias("/u/<id>", "/users/<id>")?;
+
+    let router = builder.build();
+    let stats = router.stats();
+
+    assert_eq!(stats.templates, 6);
+    assert_eq!(
+        (
+            stats.static_nodes,
+            stats.dynamic_nodes,
+            stats.wildcard_nodes,
+            stats.end_wildcard_nodes,
+        ),
+        (10, 4, 1, 1)
+    );
+    assert_eq!(stats.max_depth, 7);
+    assert_eq!(stats.revisitable_parameters, 1);
+    assert_eq!(stats.inline_searches, 1);
+    assert_eq!((stats.reachable_groups, stats.suffixes), (3, 4));
+
+    Ok(())
+}
+
+#[test]
+fn stats_growth() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/users/<id>", 1)?;
+
+    let small = builder.clone().build().stats();
+
+    builder.insert("/users/<id>/files/<name>.pdf", 2)?;
+    let large = builder.build().stats();
+
+    assert_eq!(small.inline_searches, 0);
+    assert_eq!(large.inline_searches, 1);
+    assert!(
+        small.heap_size < large.heap_size,
+        "heap size should grow with the route table"
+    );
+
+    Ok(())
+}
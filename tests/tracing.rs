@@ -0,0 +1,137 @@
+#![expect(missing_docs, reason = "Tests")]
+
+extern crate alloc;
+
+use alloc::sync::Arc;
+use core::error::Error;
+use core::fmt;
+use std::sync::{Mutex, PoisonError};
+
+use similar_asserts::assert_eq;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+use wayfind::RouterBuilder;
+
+mod common;
+use common::redact;
+
+/// Records every event and span as a line of text.
+#[derive(Clone, Default)]
+struct Recorder(Arc<Mutex<Vec<String>>>);
+
+impl Recorder {
+    fn push(&self, line: String) {
+        self.0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(line);
+    }
+
+    fn lines(&self) -> String {
+        self.0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .join("\n")
+    }
+}
+
+#[derive(Default)]
+struct Fields(Vec<String>);
+
+impl Visit for Fields {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.push(format!("{field}={value}"));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        // Durations vary between runs.
+        if field.name() == "elapsed" {
+            self.0.push(format!("{field}=[elapsed]"));
+        } else {
+            self.0.push(format!("{field}={value:?}"));
+        }
+    }
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        let mut fields = Fields::default();
+        span.record(&mut fields);
+
+        let level = span.metadata().level();
+        let name = span.metadata().name();
+        self.push(format!("{level} span {name}: {}", fields.0.join(" ")));
+
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut fields = Fields::default();
+        event.record(&mut fields);
+
+        let level = event.metadata().level();
+        self.push(format!("{level} {}", fields.0.join(" ")));
+    }
+
+    fn enter(&self, _span: &Id) {}
+
+    fn exit(&self, _span: &Id) {}
+}
+
+#[test]
+fn tracing_events() -> Result<(), Box<dyn Error>> {
+    let recorder = Recorder::default();
+
+    tracing::subscriber::with_default(recorder.clone(), || -> Result<(), Box<dyn Error>> {
+        let mut builder = RouterBuilder::new();
+        builder.insert("/users/<id>.<ext>", 1)?;
+        builder.insert("/files/<*path>", 2)?;
+        builder.alias("/u/<id>.<ext>", "/users/<id>.<ext>")?;
+        builder.insert("/users/<id", 3).unwrap_err();
+
+        builder.insert("/users/<name>.<ext>", 3).unwrap_err();
+
+        builder.upsert("/files/<*path>", 4)?;
+
+        let router = builder.build();
+        assert_eq!(
+            router
+                .search("/users/123.json")
+                .map(|search| *search.data()),
+            Some(1)
+        );
+        assert_eq!(
+            router.search("/users/123").map(|search| *search.data()),
+            None
+        );
+        assert_eq!(router.search("/").map(|search| *search.data()), None);
+
+        Ok(())
+    })?;
+
+    insta::assert_snapshot!(redact(&recorder.lines()), @r#"
+    TRACE message=inserting template template=/users/<id>.<ext> parts=["/users/", "<id>", ".", "<ext>"]
+    TRACE message=inserting template template=/files/<*path> parts=["/files/", "<*path>"]
+    TRACE message=inserting alias template=/u/<id>.<ext> parts=["/u/", "<id>", ".", "<ext>"]
+    DEBUG message=rejected template template=/users/<id error=unbalanced angle bracket
+    TRACE message=inserting template template=/users/<name>.<ext> parts=["/users/", "<name>", ".", "<ext>"]
+    DEBUG message=rejected template template=/users/<name>.<ext> error=conflicts with `/users/<id>.<ext>` (inserted at [location]), both match `/users/x.x`
+    TRACE message=inserting template template=/files/<*path> parts=["/files/", "<*path>"]
+    DEBUG span build: routes=2
+    DEBUG message=compiled router routes=2 elapsed=[elapsed]
+    TRACE message=matched path path=/users/123.json template=/users/<id>.<ext>
+    TRACE message=unmatched path path=/users/123 reason=no matching template
+    TRACE message=unmatched path path=/ reason=path length out of bounds
+    "#);
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+#![expect(missing_docs, reason = "Tests")]
+
+use core::error::Error;
+
+use similar_asserts::assert_eq;
+use wayfind::{Entry, InsertError, RouterBuilder};
+
+mod common;
+use common::redact;
+
+#[test]
+fn upsert_replace() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    assert_eq!(builder.upsert("/users/<id>", 1)?, None);
+    assert_eq!(builder.upsert("/users/<id>/files/<*path>", 2)?, None);
+    assert_eq!(builder.upsert("/<*catch_all>", 3)?, None);
+
+    assert_eq!(builder.upsert("/users/<id>", 4)?, Some(1));
+    assert_eq!(builder.upsert("/users/<id>/files/<*path>", 5)?, Some(2));
+    assert_eq!(builder.upsert("/<*catch_all>", 6)?, Some(3));
+
+    let router = builder.build();
+    insta::assert_snapshot!(router, @r"
+    /
+    ├─ users/
+    │  ╰─ <id>
+    │     ╰─ /files/
+    │        ╰─ <*path>
+    ╰─ <*catch_all>
+    ");
+
+    assert_eq!(router.search("/users/123").unwrap().data(), &4);
+    assert_eq!(router.search("/users/123/files/a/b").unwrap().data(), &5);
+    assert_eq!(router.search("/other").unwrap().data(), &6);
+
+    Ok(())
+}
+
+#[test]
+fn upsert_conflict() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.upsert("/users/<id>", 1)?;
+
+    let error = builder.upsert("/users/<name>", 2).unwrap_err();
+    insta::assert_snapshot!(redact(&error), @"conflicts with `/users/<id>` (inserted at [location]), both match `/users/x`");
+
+    let error = builder.upsert("users", 3).unwrap_err();
+    assert_eq!(
+        error,
+        InsertError::MissingSlash {
+            template: "users".to_owned(),
+            span: 0..1,
+        }
+    );
+
+    let router = builder.build();
+    assert_eq!(router.search("/users/123").unwrap().data(), &1);
+
+    Ok(())
+}
+
+#[test]
+fn upsert_entry() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/users", vec!["list"])?;
+
+    let Entry::Occupied(mut entry) = builder.entry("/users")? else {
+        return Err("expected an occupied entry".into());
+    };
+
+    assert_eq!(entry.template(), "/users");
+    entry.get_mut().push("create");
+
+    let Entry::Vacant(entry) = builder.entry("/posts")? else {
+        return Err("expected a vacant entry".into());
+    };
+
+    assert_eq!(entry.template(), "/posts");
+    entry.insert(vec!["list"]);
+
+    builder
+        .entry("/posts")?
+        .and_modify(|methods| methods.push("create"))
+        .or_insert_with(Vec::new);
+
+    builder.entry("/tags")?.or_default().push("list");
+
+    let router = builder.build();
+    assert_eq!(router.search("/users").unwrap().data(), &["list", "create"]);
+    assert_eq!(router.search("/posts").unwrap().data(), &["list", "create"]);
+    assert_eq!(router.search("/tags").unwrap().data(), &["list"]);
+
+    Ok(())
+}
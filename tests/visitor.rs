@@ -0,0 +1,102 @@
+#![expect(missing_docs, reason = "Tests")]
+
+use core::error::Error;
+
+use similar_asserts::assert_eq;
+use wayfind::{NodeKind, NodeRef, RouterBuilder, SearchMode, Visitor};
+
+/// Prints every node on its own line, indented by depth.
+#[derive(Default)]
+struct Printer {
+    depth: usize,
+    lines: Vec<String>,
+}
+
+impl<T> Visitor<'_, T> for Printer {
+    fn enter(&mut self, node: NodeRef<'_, T>) -> bool {
+        let mut parts = vec![format!("{}{}", "  ".repeat(self.depth), node.kind())];
+
+        if let Some(prefix) = node.prefix() {
+            parts.push(format!("{:?}", String::from_utf8_lossy(prefix)));
+        }
+
+        if let Some(name) = node.name() {
+            parts.push(name.to_owned());
+        }
+
+        if let Some(bounds) = node.bounds() {
+            parts.push(match bounds.longest() {
+                usize::MAX => format!("[{}..]", bounds.shortest()),
+                longest => format!("[{}..={longest}]", bounds.shortest()),
+            });
+        }
+
+        if let Some(template) = node.template() {
+            parts.push(format!("=> {template}"));
+        }
+
+        self.lines.push(parts.join(" "));
+        self.depth += 1;
+        true
+    }
+
+    fn leave(&mut self, _node: NodeRef<'_, T>) {
+        self.depth -= 1;
+    }
+}
+
+#[test]
+fn visitor_walk() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/users/<id>", 1)?;
+    builder.insert("/users/<id>/files/<name>.pdf", 2)?;
+    builder.insert("/api/<*path>/help", 3)?;
+    builder.insert("/<*catch_all>", 4)?;
+
+    let router = builder.build();
+    let mut printer = Printer::default();
+    router.visit(&mut printer);
+
+    insta::assert_snapshot!(printer.lines.join("\n"), @r#"
+    root [2..]
+      static "/" [1..]
+        static "api/" [6..]
+          wildcard path [5..=5]
+            static "/help" [0..=0] => /api/<*path>/help
+        static "users/" [1..]
+          dynamic id [0..] => /users/<id>
+            static "/files/" [5..]
+              dynamic name [4..=4]
+                static ".pdf" [0..=0] => /users/<id>/files/<name>.pdf
+        end wildcard catch_all => /<*catch_all>
+    "#);
+
+    Ok(())
+}
+
+#[test]
+fn visitor_node_ref() -> Result<(), Box<dyn Error>> {
+    let mut builder = RouterBuilder::new();
+    builder.insert("/files/<name>.pdf", 1)?;
+    builder.insert("/files/<*path>", 2)?;
+
+    let router = builder.build();
+    let root = router.root();
+    assert_eq!(root.kind(), NodeKind::Root);
+    assert_eq!(root.template(), None);
+
+    let files = root.children().next().ok_or("missing child")?;
+    assert_eq!(files.prefix(), Some(&b"/files/"[..]));
+    assert_eq!(files.dynamic_search(), Some(SearchMode::Inline));
+
+    let kinds: Vec<NodeKind> = files.children().map(|child| child.kind()).collect();
+    assert_eq!(kinds, [NodeKind::Dynamic, NodeKind::EndWildcard]);
+
+    let path = files.children().last().ok_or("missing child")?;
+    assert_eq!(path.name(), Some("path"));
+    assert_eq!(path.data(), Some(&2));
+    assert_eq!(path.bounds(), None);
+    assert_eq!(path.children().count(), 0);
+
+    Ok(())
+}